@@ -0,0 +1,175 @@
+use std::{fmt::Debug, time::Instant};
+
+use rand::Rng;
+
+use crate::{
+    algorithms::{
+        adaptive_large_neighborhood_search::{
+            config::AdaptiveLargeNeighborhoodSearchConfig,
+            operators::{DestroyOperator, RepairOperator},
+        },
+        errors::AlgorithmError,
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::ProblemSolution,
+};
+
+/// Adaptive weight of an operator, updated at the end of each segment
+#[derive(Debug, Clone)]
+struct OperatorWeight {
+    /// Current weight used by the roulette wheel
+    weight: f64,
+    /// Score accumulated during the segment
+    score: f64,
+    /// Number of uses during the segment
+    uses: usize,
+}
+
+impl OperatorWeight {
+    fn new() -> Self {
+        OperatorWeight {
+            weight: 1.0,
+            score: 0.0,
+            uses: 0,
+        }
+    }
+
+    /// Blend the average segment score into the weight and start a new segment
+    fn update(&mut self, reaction_factor: f64) {
+        if self.uses > 0 {
+            self.weight = (1.0 - reaction_factor) * self.weight
+                + reaction_factor * self.score / self.uses as f64;
+        }
+        self.score = 0.0;
+        self.uses = 0;
+    }
+}
+
+/// Pick an operator with a probability proportional to its weight
+fn roulette(weights: &[OperatorWeight], rng: &mut impl Rng) -> usize {
+    let total: f64 = weights.iter().map(|w| w.weight).sum();
+    if total <= 0.0 {
+        return rng.random_range(0..weights.len());
+    }
+    let mut pointer = rng.random::<f64>() * total;
+    for (index, operator) in weights.iter().enumerate() {
+        if pointer < operator.weight {
+            return index;
+        }
+        pointer -= operator.weight;
+    }
+    weights.len() - 1
+}
+
+/// Main algorithm implementation for the adaptive large neighborhood search, where the
+/// destroy and repair operators are registered for the solution type
+pub struct AdaptiveLargeNeighborhoodSearch<T> {
+    /// Configuration object for the algorithm
+    pub config: AdaptiveLargeNeighborhoodSearchConfig,
+    /// Registered destroy operators
+    destroy_operators: Vec<Box<dyn DestroyOperator<T>>>,
+    /// Registered repair operators
+    repair_operators: Vec<Box<dyn RepairOperator<T>>>,
+}
+
+impl<T> AdaptiveLargeNeighborhoodSearch<T>
+where
+    T: Clone + Debug + ProblemSolution,
+{
+    /// Constructor to create a new AdaptiveLargeNeighborhoodSearch struct without operators
+    pub fn new(config: AdaptiveLargeNeighborhoodSearchConfig) -> Self {
+        AdaptiveLargeNeighborhoodSearch {
+            config,
+            destroy_operators: Vec::new(),
+            repair_operators: Vec::new(),
+        }
+    }
+
+    /// Register a destroy operator
+    pub fn register_destroy_operator(&mut self, operator: impl DestroyOperator<T> + 'static) {
+        self.destroy_operators.push(Box::new(operator));
+    }
+
+    /// Register a repair operator
+    pub fn register_repair_operator(&mut self, operator: impl RepairOperator<T> + 'static) {
+        self.repair_operators.push(Box::new(operator));
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError> {
+        let initial_time = Instant::now();
+        if self.destroy_operators.is_empty() || self.repair_operators.is_empty() {
+            return Err(AlgorithmError::ExecutionError(
+                "at least one destroy and one repair operator should be registered.",
+            ));
+        }
+
+        // Initialize useful mutable variables
+        let mut current_solution = initial_solution;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+        let mut temperature = self.config.initial_temperature;
+        let mut destroy_weights = vec![OperatorWeight::new(); self.destroy_operators.len()];
+        let mut repair_weights = vec![OperatorWeight::new(); self.repair_operators.len()];
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            // Destroy and repair the current solution with operators picked by roulette
+            let destroy_index = roulette(&destroy_weights, rng);
+            let repair_index = roulette(&repair_weights, rng);
+            let partial_solution = self.destroy_operators[destroy_index]
+                .destroy(&current_solution, rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not destroy the solution."))?;
+            let new_solution = self.repair_operators[repair_index]
+                .repair(&partial_solution, rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not repair the solution."))?;
+
+            // Simulated annealing acceptance, and score of the operators
+            let delta_objective = new_solution.objective() - current_solution.objective();
+            let score = if new_solution.objective() < best_solution.objective() {
+                best_solution = new_solution.clone();
+                current_solution = new_solution;
+                self.config.best_score
+            } else if delta_objective < 0.0 {
+                current_solution = new_solution;
+                self.config.improvement_score
+            } else if (-delta_objective / temperature).exp() > rng.random() {
+                current_solution = new_solution;
+                self.config.acceptance_score
+            } else {
+                0.0
+            };
+            for (weights, index) in [
+                (&mut destroy_weights, destroy_index),
+                (&mut repair_weights, repair_index),
+            ] {
+                weights[index].score += score;
+                weights[index].uses += 1;
+            }
+
+            // Update temperature, weights and iteration counter
+            temperature *= self.config.cooling_rate;
+            iteration += 1;
+            if iteration % self.config.segment_length == 0 {
+                for operator in destroy_weights.iter_mut().chain(repair_weights.iter_mut()) {
+                    operator.update(self.config.reaction_factor);
+                }
+            }
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the adaptive large neighborhood search algorithm
+#[derive(Debug, Clone)]
+pub struct AdaptiveLargeNeighborhoodSearchConfig {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Number of iterations between two updates of the operator weights
+    pub segment_length: usize,
+    /// Weight of the last segment scores in the updated operator weights
+    pub reaction_factor: f64,
+    /// Score of the operators finding a new best solution
+    pub best_score: f64,
+    /// Score of the operators improving on the current solution
+    pub improvement_score: f64,
+    /// Score of the operators finding a worse solution which is accepted
+    pub acceptance_score: f64,
+    /// Initial temperature of the acceptance criterion
+    pub initial_temperature: f64,
+    /// Geometric cooling rate of the acceptance criterion
+    pub cooling_rate: f64,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl AdaptiveLargeNeighborhoodSearchConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        max_iterations: usize,
+        segment_length: usize,
+        reaction_factor: f64,
+        best_score: f64,
+        improvement_score: f64,
+        acceptance_score: f64,
+        initial_temperature: f64,
+        cooling_rate: f64,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if segment_length == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the segments should last at least one iteration.",
+            ));
+        }
+        if !(0.0..=1.0).contains(&reaction_factor) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the reaction factor should be between 0 and 1.",
+            ));
+        }
+        if best_score < 0.0 || improvement_score < 0.0 || acceptance_score < 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the operator scores should be non-negative.",
+            ));
+        }
+        if initial_temperature <= 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the initial temperature should be positive.",
+            ));
+        }
+        if !(0.0..=1.0).contains(&cooling_rate) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the cooling rate should be between 0 and 1.",
+            ));
+        }
+        Ok(AdaptiveLargeNeighborhoodSearchConfig {
+            max_iterations,
+            segment_length,
+            reaction_factor,
+            best_score,
+            improvement_score,
+            acceptance_score,
+            initial_temperature,
+            cooling_rate,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for AdaptiveLargeNeighborhoodSearchConfig {
+    fn default() -> Self {
+        AdaptiveLargeNeighborhoodSearchConfig {
+            max_iterations: 1_000,
+            segment_length: 100,
+            reaction_factor: 0.1,
+            best_score: 33.0,
+            improvement_score: 9.0,
+            acceptance_score: 13.0,
+            initial_temperature: 10.0,
+            cooling_rate: 0.995,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod algorithm;
+pub mod config;
+pub mod operators;
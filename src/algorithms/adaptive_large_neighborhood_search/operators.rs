@@ -0,0 +1,17 @@
+use std::fmt::Debug;
+
+use rand::RngCore;
+
+use crate::problems::ProblemError;
+
+/// Trait for the operators removing part of a solution
+pub trait DestroyOperator<T>: Debug + Send + Sync {
+    /// Return the partial solution obtained by destroying part of the solution
+    fn destroy(&self, solution: &T, rng: &mut dyn RngCore) -> Result<T, ProblemError>;
+}
+
+/// Trait for the operators rebuilding a complete solution from a partial one
+pub trait RepairOperator<T>: Debug + Send + Sync {
+    /// Return the complete solution obtained by repairing the partial solution
+    fn repair(&self, solution: &T, rng: &mut dyn RngCore) -> Result<T, ProblemError>;
+}
@@ -0,0 +1,244 @@
+use std::{fmt::Debug, time::Instant};
+
+use rand::Rng;
+
+use crate::{
+    algorithms::{
+        ant_colony::config::{AntColonyConfig, AntColonyVariant},
+        errors::AlgorithmError,
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::{ProblemError, ProblemSolution},
+};
+
+/// Trait for solutions built component by component on a construction graph
+pub trait ConstructionGraph: Clone + Debug + ProblemSolution {
+    /// Number of pheromone trails of the construction graph
+    fn number_trails(&self) -> usize;
+
+    /// Empty partial solution from which every construction starts
+    fn empty_solution(&self) -> Self;
+
+    /// Components which could extend the partial solution
+    fn candidate_components(&self) -> Vec<usize>;
+
+    /// Check whether adding the component keeps the partial solution feasible
+    fn is_feasible(&self, component: usize) -> bool;
+
+    /// Heuristic desirability of adding the component, larger is better
+    fn heuristic(&self, component: usize) -> f64;
+
+    /// Pheromone trail followed when adding the component to the partial solution
+    fn trail(&self, component: usize) -> usize;
+
+    /// Extend the partial solution with the component
+    fn add_component(&self, component: usize) -> Result<Self, ProblemError>;
+}
+
+/// Main algorithm implementation for the ant colony optimization algorithm
+pub struct AntColonyAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: AntColonyConfig,
+}
+
+impl AntColonyAlgorithm {
+    /// Constructor to create a new AntColonyAlgorithm struct
+    pub fn new(config: AntColonyConfig) -> Self {
+        AntColonyAlgorithm { config }
+    }
+
+    /// Bounds of the pheromone trails
+    fn pheromone_limits(&self) -> (f64, f64) {
+        match self.config.variant {
+            AntColonyVariant::MaxMinAntSystem { min_max_ratio } => {
+                let max_pheromone = 1.0 / self.config.evaporation_rate;
+                (max_pheromone * min_max_ratio, max_pheromone)
+            }
+            _ => (0.0, f64::INFINITY),
+        }
+    }
+
+    /// Build a complete solution, returning it with the trails it followed
+    fn construct_solution<T>(
+        &self,
+        template: &T,
+        pheromones: &mut [f64],
+        rng: &mut impl Rng,
+    ) -> Result<(T, Vec<usize>), AlgorithmError>
+    where
+        T: ConstructionGraph,
+    {
+        let mut solution = template.empty_solution();
+        let mut trails: Vec<usize> = Vec::new();
+        loop {
+            let candidates: Vec<usize> = solution
+                .candidate_components()
+                .into_iter()
+                .filter(|&component| solution.is_feasible(component))
+                .collect();
+            if candidates.is_empty() {
+                break;
+            }
+
+            // Attractiveness of each candidate
+            let attractiveness: Vec<f64> = candidates
+                .iter()
+                .map(|&component| {
+                    pheromones[solution.trail(component)].powf(self.config.pheromone_weight)
+                        * solution
+                            .heuristic(component)
+                            .max(0.0)
+                            .powf(self.config.heuristic_weight)
+                })
+                .collect();
+            let total: f64 = attractiveness.iter().sum();
+
+            // Choose the next component
+            let exploit = match self.config.variant {
+                AntColonyVariant::AntColonySystem {
+                    exploitation_probability,
+                    ..
+                } => rng.random::<f64>() < exploitation_probability,
+                _ => false,
+            };
+            let choice = if exploit || total <= 0.0 || !total.is_finite() {
+                (0..candidates.len())
+                    .max_by(|&a, &b| {
+                        attractiveness[a]
+                            .partial_cmp(&attractiveness[b])
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .unwrap_or(0)
+            } else {
+                let mut pointer = rng.random::<f64>() * total;
+                let mut choice = candidates.len() - 1;
+                for (index, value) in attractiveness.iter().enumerate() {
+                    if pointer < *value {
+                        choice = index;
+                        break;
+                    }
+                    pointer -= value;
+                }
+                choice
+            };
+
+            let component = candidates[choice];
+            let trail = solution.trail(component);
+            solution = solution
+                .add_component(component)
+                .map_err(|_| AlgorithmError::ExecutionError("could not add a component."))?;
+            trails.push(trail);
+
+            // Local update of the Ant Colony System
+            if let AntColonyVariant::AntColonySystem {
+                local_evaporation_rate,
+                ..
+            } = self.config.variant
+            {
+                pheromones[trail] = (1.0 - local_evaporation_rate) * pheromones[trail]
+                    + local_evaporation_rate * self.config.initial_pheromone;
+            }
+        }
+        Ok((solution, trails))
+    }
+
+    /// Find a solution with minimal objective function. The initial solution provides the
+    /// construction graph and is returned if no ant finds a better solution.
+    /// Deposits are `1 / (1 + f - f*)`, where `f*` is the best objective found so far, so that
+    /// they do not depend on the sign or scale of the objective.
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: ConstructionGraph,
+    {
+        let initial_time = Instant::now();
+
+        // Initialize useful mutable variables
+        let (min_pheromone, max_pheromone) = self.pheromone_limits();
+        let initial_pheromone = match self.config.variant {
+            AntColonyVariant::MaxMinAntSystem { .. } => max_pheromone,
+            _ => self.config.initial_pheromone,
+        };
+        let mut pheromones = vec![initial_pheromone; initial_solution.number_trails()];
+        let mut best_solution = initial_solution.clone();
+        let mut best_construction: Option<(T, Vec<usize>)> = None;
+        let mut iteration = 0;
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            // Let every ant build a solution
+            let mut constructions: Vec<(T, Vec<usize>)> =
+                Vec::with_capacity(self.config.number_ants);
+            for _ in 0..self.config.number_ants {
+                constructions.push(self.construct_solution(
+                    &initial_solution,
+                    &mut pheromones,
+                    rng,
+                )?);
+            }
+            constructions.sort_by(|a, b| {
+                a.0.objective()
+                    .partial_cmp(&b.0.objective())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            let iteration_best = &constructions[0];
+            if best_construction
+                .as_ref()
+                .is_none_or(|(solution, _)| iteration_best.0.objective() < solution.objective())
+            {
+                best_construction = Some(iteration_best.clone());
+            }
+            if iteration_best.0.objective() < best_solution.objective() {
+                best_solution = iteration_best.0.clone();
+            }
+
+            // Evaporate then deposit pheromone
+            let rate = self.config.evaporation_rate;
+            let best_objective = best_solution.objective();
+            let deposit = |objective: f64| 1.0 / (1.0 + (objective - best_objective).max(0.0));
+            match self.config.variant {
+                AntColonyVariant::AntSystem => {
+                    pheromones.iter_mut().for_each(|p| *p *= 1.0 - rate);
+                    for (solution, trails) in constructions.iter() {
+                        let amount = deposit(solution.objective());
+                        trails.iter().for_each(|&trail| pheromones[trail] += amount);
+                    }
+                }
+                AntColonyVariant::MaxMinAntSystem { .. } => {
+                    pheromones.iter_mut().for_each(|p| *p *= 1.0 - rate);
+                    let (solution, trails) = &constructions[0];
+                    let amount = deposit(solution.objective());
+                    trails.iter().for_each(|&trail| pheromones[trail] += amount);
+                    pheromones
+                        .iter_mut()
+                        .for_each(|p| *p = p.clamp(min_pheromone, max_pheromone));
+                }
+                AntColonyVariant::AntColonySystem { .. } => {
+                    // Only the trails of the best solution evaporate and receive pheromone
+                    if let Some((solution, trails)) = best_construction.as_ref() {
+                        let amount = deposit(solution.objective());
+                        for &trail in trails {
+                            pheromones[trail] = (1.0 - rate) * pheromones[trail] + rate * amount;
+                        }
+                    }
+                }
+            }
+
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
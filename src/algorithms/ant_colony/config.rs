@@ -0,0 +1,115 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Variant of the ant colony optimization algorithm
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AntColonyVariant {
+    /// Ant System: every ant deposits pheromone
+    AntSystem,
+    /// MAX-MIN Ant System: only the best ant of the iteration deposits pheromone, and trails are
+    /// kept between `1 / evaporation_rate` and this value times `min_max_ratio`
+    MaxMinAntSystem { min_max_ratio: f64 },
+    /// Ant Colony System: pseudo-random proportional choice of the components, local
+    /// evaporation on the trails used by the ants, deposit by the best solution only
+    AntColonySystem {
+        /// Probability of greedily picking the most attractive component
+        exploitation_probability: f64,
+        /// Evaporation rate of the local update
+        local_evaporation_rate: f64,
+    },
+}
+
+/// Configuration for the ant colony optimization algorithm
+#[derive(Debug, Clone)]
+pub struct AntColonyConfig {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Number of ants building a solution at each iteration
+    pub number_ants: usize,
+    /// Exponent of the pheromone in the attractiveness of a component
+    pub pheromone_weight: f64,
+    /// Exponent of the heuristic desirability in the attractiveness of a component
+    pub heuristic_weight: f64,
+    /// Fraction of the pheromone evaporating at each iteration
+    pub evaporation_rate: f64,
+    /// Initial pheromone on all trails
+    pub initial_pheromone: f64,
+    /// Variant of the algorithm
+    pub variant: AntColonyVariant,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl AntColonyConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        max_iterations: usize,
+        number_ants: usize,
+        pheromone_weight: f64,
+        heuristic_weight: f64,
+        evaporation_rate: f64,
+        initial_pheromone: f64,
+        variant: AntColonyVariant,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if number_ants == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the colony should contain at least one ant.",
+            ));
+        }
+        if evaporation_rate <= 0.0 || evaporation_rate > 1.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the evaporation rate should be in ]0, 1].",
+            ));
+        }
+        if initial_pheromone <= 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the initial pheromone should be strictly positive.",
+            ));
+        }
+        match variant {
+            AntColonyVariant::MaxMinAntSystem { min_max_ratio }
+                if min_max_ratio <= 0.0 || min_max_ratio >= 1.0 =>
+            {
+                return Err(AlgorithmError::ConfigurationError(
+                    "the ratio between the minimal and maximal pheromone should be in ]0, 1[.",
+                ));
+            }
+            AntColonyVariant::AntColonySystem {
+                exploitation_probability,
+                local_evaporation_rate,
+            } if !(0.0..=1.0).contains(&exploitation_probability)
+                || !(0.0..=1.0).contains(&local_evaporation_rate) =>
+            {
+                return Err(AlgorithmError::ConfigurationError(
+                    "the exploitation probability and local evaporation rate should be between 0 and 1.",
+                ));
+            }
+            _ => {}
+        }
+        Ok(AntColonyConfig {
+            max_iterations,
+            number_ants,
+            pheromone_weight,
+            heuristic_weight,
+            evaporation_rate,
+            initial_pheromone,
+            variant,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for AntColonyConfig {
+    fn default() -> Self {
+        AntColonyConfig {
+            max_iterations: 100,
+            number_ants: 20,
+            pheromone_weight: 1.0,
+            heuristic_weight: 2.0,
+            evaporation_rate: 0.1,
+            initial_pheromone: 1.0,
+            variant: AntColonyVariant::AntSystem,
+            stop_threshold: None,
+        }
+    }
+}
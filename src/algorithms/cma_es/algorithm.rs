@@ -0,0 +1,342 @@
+use std::{collections::VecDeque, time::Instant};
+
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+use crate::{
+    algorithms::{
+        cma_es::{
+            config::{CmaEsConfig, CmaEsRestart},
+            linear_algebra::symmetric_eigen,
+        },
+        errors::AlgorithmError,
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::RealVectorSolution,
+};
+
+/// Maximal ratio between the largest and smallest axes of the search distribution
+const MAX_AXIS_RATIO: f64 = 1e7;
+
+/// Outcome of a single run of the evolution strategy
+struct RunOutcome<T> {
+    /// Best solution sampled during the run
+    best_solution: Option<T>,
+    /// Number of evaluations of the objective
+    evaluations: usize,
+    /// Number of generations
+    generations: usize,
+}
+
+/// Regime of a BIPOP restart
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Regime {
+    Large,
+    Small,
+}
+
+/// Main algorithm implementation for the covariance matrix adaptation evolution strategy
+pub struct CmaEsAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: CmaEsConfig,
+}
+
+impl CmaEsAlgorithm {
+    /// Constructor to create a new CmaEsAlgorithm struct
+    pub fn new(config: CmaEsConfig) -> Self {
+        CmaEsAlgorithm { config }
+    }
+
+    /// Default number of samples per generation
+    fn default_population_size(dimension: usize) -> usize {
+        4 + (3.0 * (dimension as f64).ln()).floor() as usize
+    }
+
+    /// Run the evolution strategy from the given mean and step size until it converges or the
+    /// budget of evaluations is exhausted
+    fn run<T>(
+        &self,
+        template: &T,
+        mut mean: Vec<f64>,
+        mut sigma: f64,
+        population_size: usize,
+        budget: usize,
+        rng: &mut impl Rng,
+    ) -> Result<RunOutcome<T>, AlgorithmError>
+    where
+        T: RealVectorSolution,
+    {
+        let bounds = template.bounds();
+        let n = mean.len();
+        let dimension = n as f64;
+
+        // Recombination weights
+        let lambda = population_size;
+        let mu = lambda / 2;
+        let raw_weights: Vec<f64> = (0..mu)
+            .map(|i| (mu as f64 + 0.5).ln() - ((i + 1) as f64).ln())
+            .collect();
+        let weights_sum: f64 = raw_weights.iter().sum();
+        let weights: Vec<f64> = raw_weights.iter().map(|w| w / weights_sum).collect();
+        let mueff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        // Learning rates and damping
+        let cc = (4.0 + mueff / dimension) / (dimension + 4.0 + 2.0 * mueff / dimension);
+        let cs = (mueff + 2.0) / (dimension + mueff + 5.0);
+        let c1 = 2.0 / ((dimension + 1.3).powi(2) + mueff);
+        let cmu =
+            (1.0 - c1).min(2.0 * (mueff - 2.0 + 1.0 / mueff) / ((dimension + 2.0).powi(2) + mueff));
+        let damps = 1.0 + 2.0 * (((mueff - 1.0) / (dimension + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = dimension.sqrt()
+            * (1.0 - 1.0 / (4.0 * dimension) + 1.0 / (21.0 * dimension * dimension));
+
+        // State of the search distribution
+        let identity: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+        let mut covariance = identity.clone();
+        let mut axes = identity;
+        let mut scales = vec![1.0; n];
+        let mut path_covariance = vec![0.0; n];
+        let mut path_sigma = vec![0.0; n];
+
+        let mut best_solution: Option<T> = None;
+        let mut evaluations = 0;
+        let mut generations = 0;
+        let history_length = 10 + (30.0 * dimension / lambda as f64).ceil() as usize;
+        let mut history: VecDeque<f64> = VecDeque::with_capacity(history_length + 1);
+
+        while evaluations + lambda <= budget {
+            // Sample the generation, samples outside the bounds are projected onto them
+            let mut samples: Vec<(Vec<f64>, T)> = Vec::with_capacity(lambda);
+            for _ in 0..lambda {
+                let z: Vec<f64> = (0..n).map(|_| rng.sample(StandardNormal)).collect();
+                let position: Vec<f64> = (0..n)
+                    .map(|i| {
+                        let step: f64 = (0..n).map(|j| axes[i][j] * scales[j] * z[j]).sum();
+                        (mean[i] + sigma * step).clamp(bounds[i].0, bounds[i].1)
+                    })
+                    .collect();
+                let step: Vec<f64> = (0..n).map(|i| (position[i] - mean[i]) / sigma).collect();
+                let solution = template
+                    .with_position(position)
+                    .map_err(|_| AlgorithmError::ExecutionError("could not sample a solution."))?;
+                samples.push((step, solution));
+            }
+            evaluations += lambda;
+            generations += 1;
+            samples.sort_by(|a, b| {
+                a.1.objective()
+                    .partial_cmp(&b.1.objective())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            if best_solution
+                .as_ref()
+                .is_none_or(|best| samples[0].1.objective() < best.objective())
+            {
+                best_solution = Some(samples[0].1.clone());
+            }
+            if self
+                .config
+                .stop_threshold
+                .is_some_and(|threshold| samples[0].1.objective() < threshold)
+            {
+                break;
+            }
+
+            // Move the mean towards the best samples
+            let weighted_step: Vec<f64> = (0..n)
+                .map(|i| (0..mu).map(|k| weights[k] * samples[k].0[i]).sum())
+                .collect();
+            for i in 0..n {
+                mean[i] += sigma * weighted_step[i];
+            }
+
+            // Update the evolution paths
+            let rotated: Vec<f64> = (0..n)
+                .map(|j| (0..n).map(|i| axes[i][j] * weighted_step[i]).sum::<f64>() / scales[j])
+                .collect();
+            let whitened_step: Vec<f64> = (0..n)
+                .map(|i| (0..n).map(|j| axes[i][j] * rotated[j]).sum())
+                .collect();
+            let sigma_rate = (cs * (2.0 - cs) * mueff).sqrt();
+            for i in 0..n {
+                path_sigma[i] = (1.0 - cs) * path_sigma[i] + sigma_rate * whitened_step[i];
+            }
+            let path_sigma_norm = path_sigma.iter().map(|x| x * x).sum::<f64>().sqrt();
+            let stalled_path =
+                path_sigma_norm / (1.0 - (1.0 - cs).powi(2 * generations as i32)).sqrt() / chi_n
+                    >= 1.4 + 2.0 / (dimension + 1.0);
+            let h_sigma = if stalled_path { 0.0 } else { 1.0 };
+            let covariance_rate = h_sigma * (cc * (2.0 - cc) * mueff).sqrt();
+            for i in 0..n {
+                path_covariance[i] =
+                    (1.0 - cc) * path_covariance[i] + covariance_rate * weighted_step[i];
+            }
+
+            // Update the covariance matrix with the rank-one and rank-mu updates
+            let correction = (1.0 - h_sigma) * cc * (2.0 - cc);
+            for i in 0..n {
+                for j in 0..=i {
+                    let rank_mu: f64 = (0..mu)
+                        .map(|k| weights[k] * samples[k].0[i] * samples[k].0[j])
+                        .sum();
+                    let value = (1.0 - c1 - cmu) * covariance[i][j]
+                        + c1 * (path_covariance[i] * path_covariance[j]
+                            + correction * covariance[i][j])
+                        + cmu * rank_mu;
+                    covariance[i][j] = value;
+                    covariance[j][i] = value;
+                }
+            }
+
+            // Update the step size
+            sigma *= ((cs / damps) * (path_sigma_norm / chi_n - 1.0)).exp();
+
+            // Decompose the covariance matrix
+            let (values, vectors) = symmetric_eigen(&covariance);
+            axes = vectors;
+            scales = values
+                .iter()
+                .map(|v| v.max(f64::MIN_POSITIVE).sqrt())
+                .collect();
+
+            // Termination criteria of the run
+            history.push_back(samples[0].1.objective());
+            if history.len() > history_length {
+                history.pop_front();
+            }
+            let objectives = history
+                .iter()
+                .cloned()
+                .chain(samples.iter().map(|(_, solution)| solution.objective()));
+            let (lowest, highest) = objectives
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |acc, x| {
+                    (acc.0.min(x), acc.1.max(x))
+                });
+            let largest_scale = scales.iter().cloned().fold(0.0, f64::max);
+            let smallest_scale = scales.iter().cloned().fold(f64::INFINITY, f64::min);
+            if !sigma.is_finite()
+                || sigma * largest_scale < self.config.tolerance
+                || (history.len() == history_length && highest - lowest < self.config.tolerance)
+                || largest_scale > MAX_AXIS_RATIO * smallest_scale
+            {
+                break;
+            }
+        }
+
+        Ok(RunOutcome {
+            best_solution,
+            evaluations,
+            generations,
+        })
+    }
+
+    /// Find a solution with minimal objective function, starting the search around the initial
+    /// solution. Restarts begin from uniformly drawn positions within the bounds.
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: RealVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let bounds = initial_solution.bounds().to_vec();
+        if bounds.is_empty() {
+            return Err(AlgorithmError::ExecutionError(
+                "the search space should have at least one dimension.",
+            ));
+        }
+        let average_width = bounds
+            .iter()
+            .map(|(lower, upper)| upper - lower)
+            .sum::<f64>()
+            / bounds.len() as f64;
+        let initial_sigma = self.config.initial_step_size * average_width.max(f64::EPSILON);
+        let default_size = self
+            .config
+            .population_size
+            .unwrap_or(Self::default_population_size(bounds.len()));
+
+        // State of the restarts
+        let mut best_solution = initial_solution.clone();
+        let mut mean = initial_solution.position().to_vec();
+        let (mut population_size, mut sigma) = (default_size, initial_sigma);
+        let mut regime = Regime::Large;
+        let mut large_population_size = default_size;
+        let (mut large_evaluations, mut small_evaluations) = (0, 0);
+        let (mut evaluations, mut generations) = (0, 0);
+        let mut restart_generations: Vec<usize> = Vec::new();
+
+        loop {
+            let outcome = self.run(
+                &initial_solution,
+                mean,
+                sigma,
+                population_size,
+                self.config.max_evaluations - evaluations,
+                rng,
+            )?;
+            evaluations += outcome.evaluations;
+            generations += outcome.generations;
+            match regime {
+                Regime::Large => large_evaluations += outcome.evaluations,
+                Regime::Small => small_evaluations += outcome.evaluations,
+            }
+            if let Some(solution) = outcome.best_solution
+                && solution.objective() < best_solution.objective()
+            {
+                best_solution = solution;
+            }
+
+            // Stop if the target is reached or if no restart is possible
+            let target_reached = self
+                .config
+                .stop_threshold
+                .is_some_and(|threshold| best_solution.objective() < threshold);
+            if target_reached
+                || outcome.evaluations == 0
+                || evaluations >= self.config.max_evaluations
+                || restart_generations.len() >= self.config.max_restarts
+            {
+                break;
+            }
+
+            // Prepare the next run
+            (population_size, sigma, regime) = match self.config.restart_strategy {
+                CmaEsRestart::Disabled => break,
+                CmaEsRestart::Ipop { increase_factor } => (
+                    (population_size as f64 * increase_factor).ceil() as usize,
+                    initial_sigma,
+                    Regime::Large,
+                ),
+                CmaEsRestart::Bipop if small_evaluations < large_evaluations => {
+                    let u: f64 = rng.random();
+                    let ratio = 0.5 * large_population_size as f64 / default_size as f64;
+                    let size = (default_size as f64 * ratio.powf(u * u)).floor() as usize;
+                    (
+                        size.max(2),
+                        initial_sigma * 10.0_f64.powf(-2.0 * rng.random::<f64>()),
+                        Regime::Small,
+                    )
+                }
+                CmaEsRestart::Bipop => {
+                    large_population_size *= 2;
+                    (large_population_size, initial_sigma, Regime::Large)
+                }
+            };
+            mean = bounds
+                .iter()
+                .map(|&(lower, upper)| rng.random_range(lower..=upper))
+                .collect();
+            restart_generations.push(generations);
+        }
+
+        // Return the solution
+        let mut result = SimulationResult::new(best_solution, initial_time, generations);
+        result.restart_iterations = restart_generations;
+        Ok(result)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Restart strategy of the CMA-ES algorithm
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CmaEsRestart {
+    /// Single run without restart
+    Disabled,
+    /// IPOP: restart with a population size multiplied by the given factor
+    Ipop { increase_factor: f64 },
+    /// BIPOP: alternate between increasing populations and small populations with small steps
+    Bipop,
+}
+
+/// Configuration for the covariance matrix adaptation evolution strategy
+#[derive(Debug, Clone)]
+pub struct CmaEsConfig {
+    /// Maximum number of evaluations of the objective, over all restarts
+    pub max_evaluations: usize,
+    /// Number of samples per generation, defaults to `4 + 3 ln(n)` if not provided
+    pub population_size: Option<usize>,
+    /// Initial step size, as a fraction of the average width of the bounds
+    pub initial_step_size: f64,
+    /// Restart strategy
+    pub restart_strategy: CmaEsRestart,
+    /// Maximum number of restarts
+    pub max_restarts: usize,
+    /// Tolerance on the step size and on the variations of the objective ending a run
+    pub tolerance: f64,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl CmaEsConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        max_evaluations: usize,
+        population_size: Option<usize>,
+        initial_step_size: f64,
+        restart_strategy: CmaEsRestart,
+        max_restarts: usize,
+        tolerance: f64,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if population_size.is_some_and(|size| size < 2) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the population should contain at least two samples.",
+            ));
+        }
+        if initial_step_size <= 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the initial step size should be strictly positive.",
+            ));
+        }
+        if let CmaEsRestart::Ipop { increase_factor } = restart_strategy
+            && increase_factor < 1.0
+        {
+            return Err(AlgorithmError::ConfigurationError(
+                "the population increase factor should be at least 1.",
+            ));
+        }
+        if tolerance < 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the tolerance should be positive.",
+            ));
+        }
+        Ok(CmaEsConfig {
+            max_evaluations,
+            population_size,
+            initial_step_size,
+            restart_strategy,
+            max_restarts,
+            tolerance,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for CmaEsConfig {
+    fn default() -> Self {
+        CmaEsConfig {
+            max_evaluations: 20_000,
+            population_size: None,
+            initial_step_size: 0.3,
+            restart_strategy: CmaEsRestart::Disabled,
+            max_restarts: 9,
+            tolerance: 1e-12,
+            stop_threshold: None,
+        }
+    }
+}
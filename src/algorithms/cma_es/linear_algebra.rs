@@ -0,0 +1,49 @@
+/// Eigen decomposition of a symmetric matrix with the cyclic Jacobi method.
+/// Returns the eigenvalues and the matrix whose columns are the eigenvectors.
+pub fn symmetric_eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let size = matrix.len();
+    let mut a: Vec<Vec<f64>> = matrix.to_vec();
+    let mut vectors: Vec<Vec<f64>> = (0..size)
+        .map(|i| (0..size).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    for _ in 0..100 {
+        let off_diagonal: f64 = (0..size)
+            .flat_map(|p| (p + 1..size).map(move |q| (p, q)))
+            .map(|(p, q)| a[p][q] * a[p][q])
+            .sum();
+        let diagonal: f64 = (0..size).map(|p| a[p][p] * a[p][p]).sum();
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * diagonal {
+            break;
+        }
+        for p in 0..size {
+            for q in p + 1..size {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                // Rotation cancelling the (p, q) coefficient
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (row_p, row_q) = (a[p].clone(), a[q].clone());
+                let rows = row_p.iter().zip(row_q.iter());
+                a[p] = rows.clone().map(|(apk, aqk)| c * apk - s * aqk).collect();
+                a[q] = rows.map(|(apk, aqk)| s * apk + c * aqk).collect();
+                for row in vectors.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    let values = (0..size).map(|p| a[p][p]).collect();
+    (values, vectors)
+}
@@ -0,0 +1,174 @@
+use std::time::Instant;
+
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+use crate::{
+    algorithms::{
+        cross_entropy_method::config::CrossEntropyMethodConfig,
+        errors::AlgorithmError,
+        estimation_of_distribution::algorithm::{initial_probabilities, sample_population},
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::{BinaryVectorSolution, RealVectorSolution},
+};
+
+/// Main algorithm implementation for the cross-entropy method
+pub struct CrossEntropyMethod {
+    /// Configuration object for the algorithm
+    pub config: CrossEntropyMethodConfig,
+}
+
+impl CrossEntropyMethod {
+    /// Constructor to create a new CrossEntropyMethod struct
+    pub fn new(config: CrossEntropyMethodConfig) -> Self {
+        CrossEntropyMethod { config }
+    }
+
+    /// Update a parameter towards its new estimate
+    fn smooth(&self, old: f64, new: f64) -> f64 {
+        self.config.smoothing_factor * new + (1.0 - self.config.smoothing_factor) * old
+    }
+
+    /// Find a solution with minimal objective function by sampling bits from independent
+    /// Bernoulli distributions, the initial solution only gives the number of bits and the
+    /// initial best solution
+    pub fn execute_discrete<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: BinaryVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let mut probabilities = initial_probabilities(&initial_solution)?;
+        let mut best_solution = initial_solution.clone();
+        let number_elites = self.config.number_elites();
+        let mut generation: usize = 0;
+
+        while generation < self.config.number_generations {
+            let samples = sample_population(
+                &initial_solution,
+                &probabilities,
+                self.config.sample_size,
+                rng,
+            )?;
+            if samples[0].objective() < best_solution.objective() {
+                best_solution = samples[0].clone();
+            }
+
+            // Estimate the probabilities from the elite samples
+            let mut counts = vec![0usize; probabilities.len()];
+            for solution in samples.iter().take(number_elites) {
+                for (count, bit) in counts.iter_mut().zip(solution.bits()) {
+                    *count += bit as usize;
+                }
+            }
+            for (probability, count) in probabilities.iter_mut().zip(counts) {
+                *probability = self.smooth(*probability, count as f64 / number_elites as f64);
+            }
+            generation += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        Ok(SimulationResult::new(
+            best_solution,
+            initial_time,
+            generation,
+        ))
+    }
+
+    /// Find a solution with minimal objective function by sampling coordinates from independent
+    /// Gaussian distributions, centered on the initial solution at first
+    pub fn execute_continuous<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: RealVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let bounds = initial_solution.bounds().to_vec();
+        if bounds.is_empty() {
+            return Err(AlgorithmError::ExecutionError(
+                "the search space should have at least one dimension.",
+            ));
+        }
+        let mut means = initial_solution.position().to_vec();
+        let mut standard_deviations: Vec<f64> = bounds
+            .iter()
+            .map(|(lower, upper)| self.config.initial_standard_deviation * (upper - lower))
+            .collect();
+        let mut best_solution = initial_solution.clone();
+        let number_elites = self.config.number_elites();
+        let mut generation: usize = 0;
+
+        while generation < self.config.number_generations {
+            // Sample positions within the bounds
+            let mut samples = (0..self.config.sample_size)
+                .map(|_| {
+                    let position: Vec<f64> = means
+                        .iter()
+                        .zip(standard_deviations.iter())
+                        .zip(bounds.iter())
+                        .map(|((mean, deviation), (lower, upper))| {
+                            let noise: f64 = rng.sample(StandardNormal);
+                            (mean + deviation * noise).clamp(*lower, *upper)
+                        })
+                        .collect();
+                    initial_solution.with_position(position).map_err(|_| {
+                        AlgorithmError::ExecutionError("could not generate new solution.")
+                    })
+                })
+                .collect::<Result<Vec<T>, AlgorithmError>>()?;
+            samples.sort_by(|a, b| {
+                a.objective()
+                    .partial_cmp(&b.objective())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            if samples[0].objective() < best_solution.objective() {
+                best_solution = samples[0].clone();
+            }
+
+            // Estimate the means and standard deviations from the elite samples
+            let elites = &samples[..number_elites];
+            for index in 0..means.len() {
+                let mean = elites
+                    .iter()
+                    .map(|solution| solution.position()[index])
+                    .sum::<f64>()
+                    / number_elites as f64;
+                let variance = elites
+                    .iter()
+                    .map(|solution| (solution.position()[index] - mean).powi(2))
+                    .sum::<f64>()
+                    / number_elites as f64;
+                means[index] = self.smooth(means[index], mean);
+                standard_deviations[index] =
+                    self.smooth(standard_deviations[index], variance.sqrt());
+            }
+            generation += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        Ok(SimulationResult::new(
+            best_solution,
+            initial_time,
+            generation,
+        ))
+    }
+}
@@ -0,0 +1,77 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the cross-entropy method
+#[derive(Debug, Clone)]
+pub struct CrossEntropyMethodConfig {
+    /// Generations
+    pub number_generations: usize,
+    /// Number of solutions sampled per generation
+    pub sample_size: usize,
+    /// Fraction of the best samples from which the parameters are estimated
+    pub elite_fraction: f64,
+    /// Weight of the new estimate when updating the parameters, 1 keeps no memory of the old ones
+    pub smoothing_factor: f64,
+    /// Initial standard deviation of the Gaussian parameters, as a fraction of the width of the
+    /// bounds
+    pub initial_standard_deviation: f64,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl CrossEntropyMethodConfig {
+    pub fn new(
+        number_generations: usize,
+        sample_size: usize,
+        elite_fraction: f64,
+        smoothing_factor: f64,
+        initial_standard_deviation: f64,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if sample_size == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "at least one solution should be sampled per generation.",
+            ));
+        }
+        if elite_fraction <= 0.0 || elite_fraction > 1.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the elite fraction should be in ]0, 1].",
+            ));
+        }
+        if smoothing_factor <= 0.0 || smoothing_factor > 1.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the smoothing factor should be in ]0, 1].",
+            ));
+        }
+        if initial_standard_deviation <= 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the initial standard deviation should be strictly positive.",
+            ));
+        }
+        Ok(CrossEntropyMethodConfig {
+            number_generations,
+            sample_size,
+            elite_fraction,
+            smoothing_factor,
+            initial_standard_deviation,
+            stop_threshold,
+        })
+    }
+
+    /// Number of elite samples per generation, at least one
+    pub fn number_elites(&self) -> usize {
+        ((self.elite_fraction * self.sample_size as f64).ceil() as usize).clamp(1, self.sample_size)
+    }
+}
+
+impl Default for CrossEntropyMethodConfig {
+    fn default() -> Self {
+        CrossEntropyMethodConfig {
+            number_generations: 200,
+            sample_size: 100,
+            elite_fraction: 0.1,
+            smoothing_factor: 0.7,
+            initial_standard_deviation: 0.3,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use std::time::Instant;
+
+use rand::{Rng, seq::index::sample};
+use rand_distr::{Cauchy, Distribution, Normal};
+
+use crate::{
+    algorithms::{
+        differential_evolution::config::{
+            DifferentialEvolutionConfig, DifferentialEvolutionStrategy, ParameterAdaptation,
+        },
+        errors::AlgorithmError,
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::RealVectorSolution,
+};
+
+/// Historical memory of successful parameters used by SHADE
+struct ParameterMemory {
+    /// Memory of scaling factors
+    scaling_factors: Vec<f64>,
+    /// Memory of crossover rates
+    crossover_rates: Vec<f64>,
+    /// Next entry to update
+    position: usize,
+}
+
+impl ParameterMemory {
+    pub fn new(memory_size: usize, scaling_factor: f64, crossover_rate: f64) -> Self {
+        ParameterMemory {
+            scaling_factors: vec![scaling_factor; memory_size],
+            crossover_rates: vec![crossover_rate; memory_size],
+            position: 0,
+        }
+    }
+    /// Sample a scaling factor and a crossover rate around a random entry of the memory
+    pub fn sample(&self, rng: &mut impl Rng) -> Result<(f64, f64), AlgorithmError> {
+        let entry = rng.random_range(0..self.scaling_factors.len());
+        let crossover_rate = Normal::new(self.crossover_rates[entry], 0.1)
+            .map_err(|_| AlgorithmError::ExecutionError("invalid crossover rate memory."))?
+            .sample(rng)
+            .clamp(0.0, 1.0);
+        let cauchy = Cauchy::new(self.scaling_factors[entry], 0.1)
+            .map_err(|_| AlgorithmError::ExecutionError("invalid scaling factor memory."))?;
+        let mut scaling_factor = cauchy.sample(rng);
+        while scaling_factor <= 0.0 {
+            scaling_factor = cauchy.sample(rng);
+        }
+        Ok((scaling_factor.min(1.0), crossover_rate))
+    }
+    /// Store the weighted means of the successful parameters of the last generation
+    pub fn update(&mut self, successes: &[(f64, f64, f64)]) {
+        let total_weight: f64 = successes.iter().map(|(_, _, weight)| weight).sum();
+        if successes.is_empty() || total_weight <= 0.0 {
+            return;
+        }
+        // Weighted Lehmer mean for the scaling factor, weighted mean for the crossover rate
+        let (squares, sum, crossover) = successes.iter().fold(
+            (0.0, 0.0, 0.0),
+            |(squares, sum, crossover), (scaling_factor, crossover_rate, weight)| {
+                let weight = weight / total_weight;
+                (
+                    squares + weight * scaling_factor * scaling_factor,
+                    sum + weight * scaling_factor,
+                    crossover + weight * crossover_rate,
+                )
+            },
+        );
+        self.scaling_factors[self.position] = squares / sum;
+        self.crossover_rates[self.position] = crossover;
+        self.position = (self.position + 1) % self.scaling_factors.len();
+    }
+}
+
+/// Main differential evolution algorithm to solve continuous optimization problems
+pub struct DifferentialEvolution {
+    pub config: DifferentialEvolutionConfig,
+}
+
+impl DifferentialEvolution {
+    pub fn new(config: DifferentialEvolutionConfig) -> Self {
+        Self { config }
+    }
+
+    /// Draw distinct random indices of the population, all different from the target
+    fn random_indices(
+        &self,
+        target: usize,
+        amount: usize,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<usize>, AlgorithmError> {
+        let size = self.config.population_size;
+        if amount >= size {
+            return Err(AlgorithmError::ExecutionError(
+                "the population is too small for the chosen strategy",
+            ));
+        }
+        Ok(sample(rng, size - 1, amount)
+            .into_iter()
+            .map(|index| if index >= target { index + 1 } else { index })
+            .collect())
+    }
+
+    /// Build the trial vector of the target through mutation and crossover
+    fn trial_position<T: RealVectorSolution>(
+        &self,
+        population: &[T],
+        target: usize,
+        best: usize,
+        scaling_factor: f64,
+        crossover_rate: f64,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<f64>, AlgorithmError> {
+        let position = |index: usize| population[index].position();
+        let current = position(target);
+        let dimension = current.len();
+
+        // Mutation
+        let mutant: Vec<f64> = match self.config.strategy {
+            DifferentialEvolutionStrategy::Rand1Bin => {
+                let r = self.random_indices(target, 3, rng)?;
+                (0..dimension)
+                    .map(|j| {
+                        position(r[0])[j] + scaling_factor * (position(r[1])[j] - position(r[2])[j])
+                    })
+                    .collect()
+            }
+            DifferentialEvolutionStrategy::Best1Bin => {
+                let r = self.random_indices(target, 2, rng)?;
+                (0..dimension)
+                    .map(|j| {
+                        position(best)[j] + scaling_factor * (position(r[0])[j] - position(r[1])[j])
+                    })
+                    .collect()
+            }
+            DifferentialEvolutionStrategy::CurrentToBest1Bin => {
+                let r = self.random_indices(target, 2, rng)?;
+                (0..dimension)
+                    .map(|j| {
+                        current[j]
+                            + scaling_factor * (position(best)[j] - current[j])
+                            + scaling_factor * (position(r[0])[j] - position(r[1])[j])
+                    })
+                    .collect()
+            }
+            DifferentialEvolutionStrategy::Rand2Exp => {
+                let r = self.random_indices(target, 5, rng)?;
+                (0..dimension)
+                    .map(|j| {
+                        position(r[0])[j]
+                            + scaling_factor * (position(r[1])[j] - position(r[2])[j])
+                            + scaling_factor * (position(r[3])[j] - position(r[4])[j])
+                    })
+                    .collect()
+            }
+        };
+
+        // Crossover
+        let mut trial = current.to_vec();
+        match self.config.strategy {
+            DifferentialEvolutionStrategy::Rand2Exp => {
+                // Copy a block of consecutive coordinates, starting at a random one
+                let start = rng.random_range(0..dimension);
+                let mut length = 0;
+                loop {
+                    let j = (start + length) % dimension;
+                    trial[j] = mutant[j];
+                    length += 1;
+                    if length >= dimension || rng.random::<f64>() >= crossover_rate {
+                        break;
+                    }
+                }
+            }
+            _ => {
+                // At least one coordinate is taken from the mutant
+                let forced = rng.random_range(0..dimension);
+                for j in 0..dimension {
+                    if j == forced || rng.random::<f64>() < crossover_rate {
+                        trial[j] = mutant[j];
+                    }
+                }
+            }
+        }
+
+        // Coordinates leaving the bounds are moved halfway between the target and the bound
+        for (j, &(lower, upper)) in population[target].bounds().iter().enumerate() {
+            if trial[j] < lower {
+                trial[j] = (current[j] + lower) / 2.0;
+            } else if trial[j] > upper {
+                trial[j] = (current[j] + upper) / 2.0;
+            }
+        }
+        Ok(trial)
+    }
+
+    /// Index of the best individual of the population
+    fn best_index<T: RealVectorSolution>(population: &[T]) -> usize {
+        (0..population.len())
+            .min_by(|&a, &b| {
+                population[a]
+                    .objective()
+                    .partial_cmp(&population[b].objective())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap_or(0)
+    }
+
+    /// Find a solution with minimal objective function, the initial solution is part of the
+    /// population and the other individuals are drawn uniformly within the bounds
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: RealVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let mut generation: usize = 0;
+        let size = self.config.population_size;
+        if size < self.config.strategy.required_population_size() {
+            return Err(AlgorithmError::ExecutionError(
+                "the population is too small for the chosen strategy",
+            ));
+        }
+        if let ParameterAdaptation::Shade { memory_size: 0 } = self.config.adaptation {
+            return Err(AlgorithmError::ConfigurationError(
+                "the SHADE memory should contain at least one entry.",
+            ));
+        }
+
+        // Create the initial population
+        let bounds = initial_solution.bounds().to_vec();
+        let mut population: Vec<T> = Vec::with_capacity(size);
+        population.push(initial_solution);
+        while population.len() < size {
+            let position = bounds
+                .iter()
+                .map(|&(lower, upper)| rng.random_range(lower..=upper))
+                .collect();
+            let individual = population[0].with_position(position).map_err(|_| {
+                AlgorithmError::ExecutionError("could not generate the initial population")
+            })?;
+            population.push(individual);
+        }
+
+        // Parameters of each individual and memory used by the adaptive variants
+        let mut scaling_factors = vec![self.config.scaling_factor; size];
+        let mut crossover_rates = vec![self.config.crossover_rate; size];
+        let mut memory = match self.config.adaptation {
+            ParameterAdaptation::Shade { memory_size } => Some(ParameterMemory::new(
+                memory_size,
+                self.config.scaling_factor,
+                self.config.crossover_rate,
+            )),
+            _ => None,
+        };
+
+        // Iterate over generations
+        while generation < self.config.number_generations {
+            let best = Self::best_index(&population);
+            let mut next_population = Vec::with_capacity(size);
+            let mut successes: Vec<(f64, f64, f64)> = Vec::new();
+            for target in 0..size {
+                // Pick the parameters of the trial
+                let (scaling_factor, crossover_rate) = match self.config.adaptation {
+                    ParameterAdaptation::Fixed => {
+                        (self.config.scaling_factor, self.config.crossover_rate)
+                    }
+                    ParameterAdaptation::JDE {
+                        tau_scaling_factor,
+                        tau_crossover_rate,
+                    } => {
+                        let scaling_factor = match rng.random::<f64>() < tau_scaling_factor {
+                            true => 0.1 + 0.9 * rng.random::<f64>(),
+                            false => scaling_factors[target],
+                        };
+                        let crossover_rate = match rng.random::<f64>() < tau_crossover_rate {
+                            true => rng.random::<f64>(),
+                            false => crossover_rates[target],
+                        };
+                        (scaling_factor, crossover_rate)
+                    }
+                    ParameterAdaptation::Shade { .. } => match &memory {
+                        Some(memory) => memory.sample(rng)?,
+                        None => (self.config.scaling_factor, self.config.crossover_rate),
+                    },
+                };
+
+                // Generate the trial and keep the best of the trial and the target
+                let position = self.trial_position(
+                    &population,
+                    target,
+                    best,
+                    scaling_factor,
+                    crossover_rate,
+                    rng,
+                )?;
+                let trial = population[target]
+                    .with_position(position)
+                    .map_err(|_| AlgorithmError::ExecutionError("could not generate trial"))?;
+                let improvement = population[target].objective() - trial.objective();
+                if improvement >= 0.0 {
+                    scaling_factors[target] = scaling_factor;
+                    crossover_rates[target] = crossover_rate;
+                    if improvement > 0.0 {
+                        successes.push((scaling_factor, crossover_rate, improvement));
+                    }
+                    next_population.push(trial);
+                } else {
+                    next_population.push(population[target].clone());
+                }
+            }
+            population = next_population;
+            if let Some(memory) = memory.as_mut() {
+                memory.update(&successes);
+            }
+
+            // Update the generation parameter
+            generation += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (population[Self::best_index(&population)].objective()
+                    < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+        let best_solution = population.swap_remove(Self::best_index(&population));
+        let result = SimulationResult::new(best_solution, initial_time, generation);
+        Ok(result)
+    }
+}
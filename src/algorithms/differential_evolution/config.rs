@@ -0,0 +1,134 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Mutation and crossover scheme, following the DE/x/y/z naming
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DifferentialEvolutionStrategy {
+    /// DE/rand/1/bin: random base vector, one difference, binomial crossover
+    Rand1Bin,
+    /// DE/best/1/bin: best base vector, one difference, binomial crossover
+    Best1Bin,
+    /// DE/current-to-best/1/bin: move the target towards the best vector, binomial crossover
+    CurrentToBest1Bin,
+    /// DE/rand/2/exp: random base vector, two differences, exponential crossover
+    Rand2Exp,
+}
+
+impl DifferentialEvolutionStrategy {
+    /// Number of individuals involved in the creation of a trial vector
+    pub fn required_population_size(&self) -> usize {
+        match self {
+            DifferentialEvolutionStrategy::Rand1Bin => 4,
+            DifferentialEvolutionStrategy::Best1Bin => 3,
+            DifferentialEvolutionStrategy::CurrentToBest1Bin => 3,
+            DifferentialEvolutionStrategy::Rand2Exp => 6,
+        }
+    }
+}
+
+/// Control of the scaling factor and crossover rate during the run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterAdaptation {
+    /// The configured scaling factor and crossover rate are used throughout
+    Fixed,
+    /// jDE: each individual carries its own parameters, regenerated with small probabilities
+    JDE {
+        /// Probability of regenerating the scaling factor
+        tau_scaling_factor: f64,
+        /// Probability of regenerating the crossover rate
+        tau_crossover_rate: f64,
+    },
+    /// SHADE: parameters are sampled around a memory of the values which produced improvements
+    Shade {
+        /// Number of entries of the historical memory
+        memory_size: usize,
+    },
+}
+
+/// Configuration for the differential evolution algorithm
+#[derive(Debug, Clone)]
+pub struct DifferentialEvolutionConfig {
+    /// Generations
+    pub number_generations: usize,
+    /// Population size
+    pub population_size: usize,
+    /// Scaling factor of the difference vectors (initial value for adaptive variants)
+    pub scaling_factor: f64,
+    /// Crossover rate (initial value for adaptive variants)
+    pub crossover_rate: f64,
+    /// Mutation and crossover scheme
+    pub strategy: DifferentialEvolutionStrategy,
+    /// Adaptation of the scaling factor and crossover rate
+    pub adaptation: ParameterAdaptation,
+    /// Possible stop criterion
+    pub stop_threshold: Option<f64>,
+}
+
+impl Default for DifferentialEvolutionConfig {
+    fn default() -> Self {
+        Self {
+            number_generations: 1_000,
+            population_size: 30,
+            scaling_factor: 0.5,
+            crossover_rate: 0.9,
+            strategy: DifferentialEvolutionStrategy::Rand1Bin,
+            adaptation: ParameterAdaptation::Fixed,
+            stop_threshold: None,
+        }
+    }
+}
+
+impl DifferentialEvolutionConfig {
+    pub fn new(
+        number_generations: usize,
+        population_size: usize,
+        scaling_factor: f64,
+        crossover_rate: f64,
+        strategy: DifferentialEvolutionStrategy,
+        adaptation: ParameterAdaptation,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        // Validate the data
+        if population_size < strategy.required_population_size() {
+            return Err(AlgorithmError::ConfigurationError(
+                "the population is too small for the chosen strategy.",
+            ));
+        }
+        if scaling_factor <= 0.0 || scaling_factor > 2.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the scaling factor should be in ]0, 2].",
+            ));
+        }
+        if !(0.0..=1.0).contains(&crossover_rate) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the crossover rate should be between 0 and 1.",
+            ));
+        }
+        match adaptation {
+            ParameterAdaptation::JDE {
+                tau_scaling_factor,
+                tau_crossover_rate,
+            } if !(0.0..=1.0).contains(&tau_scaling_factor)
+                || !(0.0..=1.0).contains(&tau_crossover_rate) =>
+            {
+                return Err(AlgorithmError::ConfigurationError(
+                    "the jDE regeneration probabilities should be between 0 and 1.",
+                ));
+            }
+            ParameterAdaptation::Shade { memory_size: 0 } => {
+                return Err(AlgorithmError::ConfigurationError(
+                    "the SHADE memory should contain at least one entry.",
+                ));
+            }
+            _ => {}
+        }
+        Ok(DifferentialEvolutionConfig {
+            number_generations,
+            population_size,
+            scaling_factor,
+            crossover_rate,
+            strategy,
+            adaptation,
+            stop_threshold,
+        })
+    }
+}
@@ -0,0 +1,287 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::{
+    algorithms::{
+        errors::AlgorithmError,
+        estimation_of_distribution::config::{
+            CompactGeneticAlgorithmConfig, PbilConfig, UmdaConfig,
+        },
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::BinaryVectorSolution,
+};
+
+/// Largest margin keeping the marginals of the UMDA away from 0 and 1, used for short bit strings
+const MAX_MARGIN: f64 = 0.05;
+
+/// Initial probabilities of the bits, uniform over the search space
+pub(crate) fn initial_probabilities<T: BinaryVectorSolution>(
+    initial_solution: &T,
+) -> Result<Vec<f64>, AlgorithmError> {
+    let number_bits = initial_solution.bits().len();
+    if number_bits == 0 {
+        return Err(AlgorithmError::ExecutionError(
+            "the search space should have at least one bit.",
+        ));
+    }
+    Ok(vec![0.5; number_bits])
+}
+
+/// Sample a solution where each bit is set independently with its probability
+fn sample<T: BinaryVectorSolution>(
+    template: &T,
+    probabilities: &[f64],
+    rng: &mut impl Rng,
+) -> Result<T, AlgorithmError> {
+    let bits: Vec<bool> = probabilities
+        .iter()
+        .map(|probability| rng.random::<f64>() < *probability)
+        .collect();
+    template
+        .with_bits(&bits)
+        .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))
+}
+
+/// Sample a population sorted with the best objective first
+pub(crate) fn sample_population<T: BinaryVectorSolution>(
+    template: &T,
+    probabilities: &[f64],
+    population_size: usize,
+    rng: &mut impl Rng,
+) -> Result<Vec<T>, AlgorithmError> {
+    let mut population = (0..population_size)
+        .map(|_| sample(template, probabilities, rng))
+        .collect::<Result<Vec<T>, AlgorithmError>>()?;
+    population.sort_by(|a, b| {
+        a.objective()
+            .partial_cmp(&b.objective())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(population)
+}
+
+/// Main algorithm implementation for population-based incremental learning
+pub struct Pbil {
+    /// Configuration object for the algorithm
+    pub config: PbilConfig,
+}
+
+impl Pbil {
+    /// Constructor to create a new Pbil struct
+    pub fn new(config: PbilConfig) -> Self {
+        Pbil { config }
+    }
+
+    /// Find a solution with minimal objective function, the initial solution only gives the
+    /// number of bits and the initial best solution
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: BinaryVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let mut probabilities = initial_probabilities(&initial_solution)?;
+        let mut best_solution = initial_solution.clone();
+        let mut generation: usize = 0;
+        let (learning_rate, negative_learning_rate) = (
+            self.config.learning_rate,
+            self.config.negative_learning_rate,
+        );
+
+        while generation < self.config.number_generations {
+            let population = sample_population(
+                &initial_solution,
+                &probabilities,
+                self.config.population_size,
+                rng,
+            )?;
+            let best_bits = population[0].bits();
+            let worst_bits = population[population.len() - 1].bits();
+            if population[0].objective() < best_solution.objective() {
+                best_solution = population[0].clone();
+            }
+
+            // Move the probabilities towards the best sample, and away from the worst one
+            for (index, probability) in probabilities.iter_mut().enumerate() {
+                let target = if best_bits[index] { 1.0 } else { 0.0 };
+                *probability = *probability * (1.0 - learning_rate) + target * learning_rate;
+                if best_bits[index] != worst_bits[index] {
+                    *probability = *probability * (1.0 - negative_learning_rate)
+                        + target * negative_learning_rate;
+                }
+                // Mutate the probability towards a random bit
+                if rng.random::<f64>() < self.config.mutation_probability {
+                    let random_bit = if rng.random::<bool>() { 1.0 } else { 0.0 };
+                    *probability = *probability * (1.0 - self.config.mutation_shift)
+                        + random_bit * self.config.mutation_shift;
+                }
+            }
+            generation += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        Ok(SimulationResult::new(
+            best_solution,
+            initial_time,
+            generation,
+        ))
+    }
+}
+
+/// Main algorithm implementation for the univariate marginal distribution algorithm
+pub struct Umda {
+    /// Configuration object for the algorithm
+    pub config: UmdaConfig,
+}
+
+impl Umda {
+    /// Constructor to create a new Umda struct
+    pub fn new(config: UmdaConfig) -> Self {
+        Umda { config }
+    }
+
+    /// Find a solution with minimal objective function, the initial solution only gives the
+    /// number of bits and the initial best solution
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: BinaryVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let mut probabilities = initial_probabilities(&initial_solution)?;
+        let mut best_solution = initial_solution.clone();
+        let mut generation: usize = 0;
+        // Marginals are kept away from 0 and 1 so that no bit gets fixed for good
+        let margin = (1.0 / probabilities.len() as f64).min(MAX_MARGIN);
+
+        while generation < self.config.number_generations {
+            let population = sample_population(
+                &initial_solution,
+                &probabilities,
+                self.config.population_size,
+                rng,
+            )?;
+            if population[0].objective() < best_solution.objective() {
+                best_solution = population[0].clone();
+            }
+
+            // Estimate the marginals from the selected samples
+            let mut counts = vec![0usize; probabilities.len()];
+            for solution in population.iter().take(self.config.selection_size) {
+                for (count, bit) in counts.iter_mut().zip(solution.bits()) {
+                    *count += bit as usize;
+                }
+            }
+            for (probability, count) in probabilities.iter_mut().zip(counts) {
+                *probability =
+                    (count as f64 / self.config.selection_size as f64).clamp(margin, 1.0 - margin);
+            }
+            generation += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        Ok(SimulationResult::new(
+            best_solution,
+            initial_time,
+            generation,
+        ))
+    }
+}
+
+/// Main algorithm implementation for the compact genetic algorithm
+pub struct CompactGeneticAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: CompactGeneticAlgorithmConfig,
+}
+
+impl CompactGeneticAlgorithm {
+    /// Constructor to create a new CompactGeneticAlgorithm struct
+    pub fn new(config: CompactGeneticAlgorithmConfig) -> Self {
+        CompactGeneticAlgorithm { config }
+    }
+
+    /// Find a solution with minimal objective function, the initial solution only gives the
+    /// number of bits and the initial best solution. The search stops early once every
+    /// probability has converged to 0 or 1.
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: BinaryVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let mut probabilities = initial_probabilities(&initial_solution)?;
+        let mut best_solution = initial_solution.clone();
+        let mut iteration: usize = 0;
+        let step = 1.0 / self.config.virtual_population_size as f64;
+
+        while iteration < self.config.max_iterations {
+            // Let two samples compete
+            let first = sample(&initial_solution, &probabilities, rng)?;
+            let second = sample(&initial_solution, &probabilities, rng)?;
+            let (winner, loser) = if first.objective() <= second.objective() {
+                (first, second)
+            } else {
+                (second, first)
+            };
+
+            // Move the probabilities towards the winner where both samples differ
+            for ((probability, winner_bit), loser_bit) in probabilities
+                .iter_mut()
+                .zip(winner.bits())
+                .zip(loser.bits())
+            {
+                if winner_bit != loser_bit {
+                    let shift = if winner_bit { step } else { -step };
+                    *probability = (*probability + shift).clamp(0.0, 1.0);
+                }
+            }
+            if winner.objective() < best_solution.objective() {
+                best_solution = winner;
+            }
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+            // Nothing left to learn once the model has converged
+            if probabilities
+                .iter()
+                .all(|probability| *probability < 0.5 * step || *probability > 1.0 - 0.5 * step)
+            {
+                break;
+            }
+        }
+
+        Ok(SimulationResult::new(
+            best_solution,
+            initial_time,
+            iteration,
+        ))
+    }
+}
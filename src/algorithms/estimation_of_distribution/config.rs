@@ -0,0 +1,158 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the population-based incremental learning algorithm
+#[derive(Debug, Clone)]
+pub struct PbilConfig {
+    /// Generations
+    pub number_generations: usize,
+    /// Number of solutions sampled per generation
+    pub population_size: usize,
+    /// Rate at which the probabilities move towards the best sample
+    pub learning_rate: f64,
+    /// Additional rate at which the probabilities move away from the worst sample, where it
+    /// differs from the best one
+    pub negative_learning_rate: f64,
+    /// Probability of mutating each probability after the update
+    pub mutation_probability: f64,
+    /// Amount by which a mutated probability moves towards a random bit
+    pub mutation_shift: f64,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl PbilConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        number_generations: usize,
+        population_size: usize,
+        learning_rate: f64,
+        negative_learning_rate: f64,
+        mutation_probability: f64,
+        mutation_shift: f64,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if population_size < 2 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the population should contain at least two samples.",
+            ));
+        }
+        if !(0.0..=1.0).contains(&learning_rate) || !(0.0..=1.0).contains(&negative_learning_rate) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the learning rates should be between 0 and 1.",
+            ));
+        }
+        if !(0.0..=1.0).contains(&mutation_probability) || !(0.0..=1.0).contains(&mutation_shift) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the mutation probability and shift should be between 0 and 1.",
+            ));
+        }
+        Ok(PbilConfig {
+            number_generations,
+            population_size,
+            learning_rate,
+            negative_learning_rate,
+            mutation_probability,
+            mutation_shift,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for PbilConfig {
+    fn default() -> Self {
+        PbilConfig {
+            number_generations: 500,
+            population_size: 50,
+            learning_rate: 0.1,
+            negative_learning_rate: 0.075,
+            mutation_probability: 0.02,
+            mutation_shift: 0.05,
+            stop_threshold: None,
+        }
+    }
+}
+
+/// Configuration for the univariate marginal distribution algorithm
+#[derive(Debug, Clone)]
+pub struct UmdaConfig {
+    /// Generations
+    pub number_generations: usize,
+    /// Number of solutions sampled per generation
+    pub population_size: usize,
+    /// Number of best samples from which the marginals are estimated
+    pub selection_size: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl UmdaConfig {
+    pub fn new(
+        number_generations: usize,
+        population_size: usize,
+        selection_size: usize,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if selection_size == 0 || selection_size > population_size {
+            return Err(AlgorithmError::ConfigurationError(
+                "the selection size should be between 1 and the population size.",
+            ));
+        }
+        Ok(UmdaConfig {
+            number_generations,
+            population_size,
+            selection_size,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for UmdaConfig {
+    fn default() -> Self {
+        UmdaConfig {
+            number_generations: 500,
+            population_size: 100,
+            selection_size: 50,
+            stop_threshold: None,
+        }
+    }
+}
+
+/// Configuration for the compact genetic algorithm
+#[derive(Debug, Clone)]
+pub struct CompactGeneticAlgorithmConfig {
+    /// Maximum number of pairwise competitions
+    pub max_iterations: usize,
+    /// Size of the simulated population, each competition moves the probabilities by its inverse
+    pub virtual_population_size: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl CompactGeneticAlgorithmConfig {
+    pub fn new(
+        max_iterations: usize,
+        virtual_population_size: usize,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if virtual_population_size == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the virtual population size should be strictly positive.",
+            ));
+        }
+        Ok(CompactGeneticAlgorithmConfig {
+            max_iterations,
+            virtual_population_size,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for CompactGeneticAlgorithmConfig {
+    fn default() -> Self {
+        CompactGeneticAlgorithmConfig {
+            max_iterations: 10_000,
+            virtual_population_size: 50,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::Instant;
+
+use crate::{
+    algorithms::{
+        errors::AlgorithmError, exhaustive_search::config::ExhaustiveSearchConfig,
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::BinaryVectorSolution,
+};
+
+/// Main algorithm implementation for the exhaustive search, enumerating all the bit strings
+pub struct ExhaustiveSearch {
+    /// Configuration object for the algorithm
+    pub config: ExhaustiveSearchConfig,
+}
+
+impl ExhaustiveSearch {
+    /// Constructor to create a new ExhaustiveSearch struct
+    pub fn new(config: ExhaustiveSearchConfig) -> Self {
+        ExhaustiveSearch { config }
+    }
+
+    /// Check if the budget covers the whole space of the given number of bits
+    pub fn is_exhaustive(&self, number_bits: usize) -> bool {
+        u32::try_from(number_bits)
+            .ok()
+            .and_then(|bits| 1usize.checked_shl(bits))
+            .is_some_and(|size| size <= self.config.max_evaluations)
+    }
+
+    /// Find a solution with minimal objective function by enumerating the bit strings in
+    /// increasing binary order, starting from all bits unset. The initial solution only gives the
+    /// number of bits and the initial best solution.
+    pub fn execute<T>(&self, initial_solution: T) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: BinaryVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let mut bits = vec![false; initial_solution.bits().len()];
+        let mut best_solution = initial_solution.clone();
+        let mut evaluations = 0;
+
+        while evaluations < self.config.max_evaluations {
+            let solution = initial_solution
+                .with_bits(&bits)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+            if solution.objective() < best_solution.objective() {
+                best_solution = solution;
+            }
+            evaluations += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+
+            // Increment the bit string, the enumeration is over once it wraps around
+            match bits.iter().position(|bit| !bit) {
+                Some(index) => {
+                    bits[..index].fill(false);
+                    bits[index] = true;
+                }
+                None => break,
+            }
+        }
+
+        Ok(SimulationResult::new(
+            best_solution,
+            initial_time,
+            evaluations,
+        ))
+    }
+}
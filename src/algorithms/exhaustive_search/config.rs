@@ -0,0 +1,37 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the exhaustive search
+#[derive(Debug, Clone)]
+pub struct ExhaustiveSearchConfig {
+    /// Maximum number of evaluated solutions, the search is exact only if it covers the
+    /// `2^n` solutions of the space
+    pub max_evaluations: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl ExhaustiveSearchConfig {
+    pub fn new(
+        max_evaluations: usize,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if max_evaluations == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "at least one solution should be evaluated.",
+            ));
+        }
+        Ok(ExhaustiveSearchConfig {
+            max_evaluations,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for ExhaustiveSearchConfig {
+    fn default() -> Self {
+        ExhaustiveSearchConfig {
+            max_evaluations: 1 << 20,
+            stop_threshold: None,
+        }
+    }
+}
@@ -6,7 +6,13 @@ use std::{
 use rand::Rng;
 
 use crate::{
-    algorithms::{AlgorithmError, genetic_algorithm::config::GeneticAlgorithmConfig},
+    algorithms::{
+        AlgorithmError,
+        genetic_algorithm::{
+            config::GeneticAlgorithmConfig, replacement::ReplacementStrategy,
+            selection::SelectionOperator,
+        },
+    },
     problems::{ProblemError, ProblemSolution},
 };
 
@@ -21,10 +27,38 @@ pub trait GeneticCompatible: Clone + Debug + PartialOrd + ProblemSolution {
     ) -> Result<Vec<Self>, ProblemError>;
 }
 
+/// Individual of the population
+pub(crate) struct Individual<T> {
+    /// Solution carried by the individual
+    pub(crate) solution: T,
+    /// Fitness used for selection and survival, the objective of the solution unless a
+    /// Baldwinian local search evaluated it
+    pub(crate) fitness: f64,
+    /// Number of generations the individual survived
+    pub(crate) age: usize,
+}
+
+impl<T: ProblemSolution> Individual<T> {
+    /// New individual evaluated with the objective of its solution
+    pub(crate) fn new(solution: T) -> Self {
+        let fitness = solution.objective();
+        Self::with_fitness(solution, fitness)
+    }
+
+    /// New individual with the given fitness
+    pub(crate) fn with_fitness(solution: T, fitness: f64) -> Self {
+        Individual {
+            solution,
+            fitness,
+            age: 0,
+        }
+    }
+}
+
 /// Population wrapper used by the Genetic Algorithm
-struct Population<T: GeneticCompatible> {
+pub(crate) struct Population<T: GeneticCompatible> {
     /// Elements of the population, in sorted order
-    elements: Vec<T>,
+    pub(crate) elements: Vec<Individual<T>>,
 }
 
 impl<T: GeneticCompatible> Population<T> {
@@ -35,7 +69,8 @@ impl<T: GeneticCompatible> Population<T> {
     }
     /// Add elements
     pub fn add_individuals(&mut self, individuals: Vec<T>) {
-        self.elements.extend(individuals);
+        self.elements
+            .extend(individuals.into_iter().map(Individual::new));
     }
     /// Truncate elements
     pub fn truncate(&mut self, size: usize) {
@@ -43,32 +78,92 @@ impl<T: GeneticCompatible> Population<T> {
     }
     /// Sort the elements with minimal fitness first
     pub fn sort(&mut self) {
-        self.elements
-            .sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        self.elements.sort_by(|a, b| {
+            a.fitness
+                .partial_cmp(&b.fitness)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
     }
-    /// Simple top-k selection
+    /// Select pairs of parents and generate their offsprings
     pub fn generate_offspring(
         &mut self,
+        selection_operator: &dyn SelectionOperator,
         number_pairs_parents: usize,
         rng: &mut impl Rng,
     ) -> Result<Vec<T>, AlgorithmError> {
-        // First generate the offsprings
-        let offsprings_nested = (0..number_pairs_parents)
-            .map(|idx| {
-                self.elements[2 * idx]
-                    .generate_children_with(&self.elements[2 * idx + 1], rng)
+        // Select the parents
+        let objectives: Vec<f64> = self.elements.iter().map(|e| e.fitness).collect();
+        let parents = selection_operator.select(&objectives, 2 * number_pairs_parents, rng)?;
+        if parents.len() != 2 * number_pairs_parents {
+            return Err(AlgorithmError::ExecutionError(
+                "the selection operator returned the wrong number of parents",
+            ));
+        }
+
+        // Then generate the offsprings
+        let offsprings_nested = parents
+            .chunks_exact(2)
+            .map(|pair| {
+                self.elements[pair[0]]
+                    .solution
+                    .generate_children_with(&self.elements[pair[1]].solution, rng)
                     .map_err(|_| AlgorithmError::ExecutionError("could not generate offsprings"))
             })
             .collect::<Result<Vec<Vec<T>>, AlgorithmError>>()?;
         let offsprings: Vec<T> = offsprings_nested.into_iter().flatten().collect();
         Ok(offsprings)
     }
+    /// Insert the offsprings in the population according to the replacement strategy
+    pub fn replace(
+        &mut self,
+        offsprings: Vec<Individual<T>>,
+        replacement_strategy: ReplacementStrategy,
+        population_size: usize,
+    ) -> Result<(), AlgorithmError> {
+        for individual in self.elements.iter_mut() {
+            individual.age += 1;
+        }
+        // Order the individuals by priority of survival, then keep the first ones
+        let number_offsprings = offsprings.len();
+        match replacement_strategy {
+            ReplacementStrategy::MuPlusLambda => {
+                self.elements.extend(offsprings);
+                self.sort();
+            }
+            ReplacementStrategy::Generational { elitism } => {
+                self.truncate(elitism);
+                let mut offspring_population = Population::new(number_offsprings);
+                offspring_population.elements.extend(offsprings);
+                offspring_population.sort();
+                self.elements.append(&mut offspring_population.elements);
+            }
+            ReplacementStrategy::MuCommaLambda => {
+                self.elements.clear();
+                self.elements.extend(offsprings);
+                self.sort();
+            }
+            ReplacementStrategy::SteadyStateReplaceWorst => {
+                self.truncate(population_size.saturating_sub(number_offsprings));
+                self.elements.extend(offsprings);
+                self.sort();
+            }
+            ReplacementStrategy::AgeBased => {
+                // Youngest first, ties are broken by fitness thanks to the stable sort
+                self.elements.extend(offsprings);
+                self.sort();
+                self.elements.sort_by_key(|individual| individual.age);
+            }
+        }
+        self.truncate(population_size);
+        self.sort();
+        Ok(())
+    }
     /// Return the best solution
     pub fn best_individual(&self) -> Result<T, AlgorithmError> {
         if self.elements.is_empty() {
             return Err(AlgorithmError::ExecutionError("empty population"));
         }
-        Ok(self.elements[0].clone())
+        Ok(self.elements[0].solution.clone())
     }
 }
 
@@ -102,6 +197,60 @@ impl GeneticAlgorithm {
         Self { config }
     }
 
+    /// Create the sorted population from the initial elements
+    pub(crate) fn initial_population<T>(&self, initial_elements: Vec<T>) -> Population<T>
+    where
+        T: GeneticCompatible,
+    {
+        let capacity = self.config.population_size + 2 * self.config.number_pairs_parents;
+        let mut population: Population<T> = Population::new(capacity);
+        population.add_individuals(initial_elements);
+        population.sort();
+        population
+    }
+
+    /// Select parents from the population and generate mutated offsprings
+    pub(crate) fn breed<T>(
+        &self,
+        population: &mut Population<T>,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<T>, AlgorithmError>
+    where
+        T: GeneticCompatible,
+    {
+        // Generate offsprings
+        let mut offsprings = population.generate_offspring(
+            self.config.selection_operator.as_ref(),
+            self.config.number_pairs_parents,
+            rng,
+        )?;
+        // Mutate offsprings with a probability
+        for offspring in offsprings.iter_mut() {
+            offspring
+                .mutate(self.config.mutation_rate, rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not mutate offspring"))?;
+        }
+        Ok(offsprings)
+    }
+
+    /// Evolve the population by one generation
+    pub(crate) fn evolve<T>(
+        &self,
+        population: &mut Population<T>,
+        rng: &mut impl Rng,
+    ) -> Result<(), AlgorithmError>
+    where
+        T: GeneticCompatible,
+    {
+        let offsprings = self.breed(population, rng)?;
+        // Build the next generation from the population and the offsprings
+        population.replace(
+            offsprings.into_iter().map(Individual::new).collect(),
+            self.config.replacement_strategy,
+            self.config.population_size,
+        )
+    }
+
     pub fn execute<T>(
         &self,
         initial_elements: Vec<T>,
@@ -113,40 +262,32 @@ impl GeneticAlgorithm {
         let initial_time = Instant::now();
         let mut generation: usize = 0;
 
+        self.config.validate()?;
+
         // Create the initial population
-        let capacity = self.config.population_size + 2 * self.config.number_pairs_parents;
-        let mut population: Population<T> = Population::new(capacity);
-        population.add_individuals(initial_elements);
-        population.sort();
+        let mut population = self.initial_population(initial_elements);
+        let mut best_solution = population.best_individual()?;
 
         // Iterate over generations
         while generation < self.config.number_generations {
-            // Generate offsprings
-            let mut offsprings =
-                population.generate_offspring(self.config.number_pairs_parents, rng)?;
-            // Mutate offsprings with a probability
-            for offspring in offsprings.iter_mut() {
-                offspring
-                    .mutate(self.config.mutation_rate, rng)
-                    .map_err(|_| AlgorithmError::ExecutionError("could not mutate offspring"))?;
+            self.evolve(&mut population, rng)?;
+            // Non-elitist strategies may lose the best solution
+            let best_individual = population.best_individual()?;
+            if best_individual.objective() < best_solution.objective() {
+                best_solution = best_individual;
             }
-            // Add the offsprings to the population, sort and truncate
-            population.add_individuals(offsprings);
-            population.sort();
-            population.truncate(self.config.population_size);
 
             // Update the generation parameter
             generation += 1;
 
             // Early stopping check
             if self.config.stop_threshold.is_some()
-                && (population.best_individual()?.objective() < self.config.stop_threshold.unwrap())
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
             {
                 break;
             }
         }
-        let result =
-            GeneticAlgorithmResult::new(population.best_individual()?, initial_time, generation);
+        let result = GeneticAlgorithmResult::new(best_solution, initial_time, generation);
         Ok(result)
     }
 }
@@ -1,4 +1,12 @@
-use crate::algorithms::errors::AlgorithmError;
+use std::sync::Arc;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    genetic_algorithm::{
+        replacement::ReplacementStrategy,
+        selection::{SelectionOperator, TruncationSelection},
+    },
+};
 
 /// Configuration struct for the genetic algorithm
 #[derive(Debug, Clone)]
@@ -11,6 +19,10 @@ pub struct GeneticAlgorithmConfig {
     pub mutation_rate: f64,
     /// Number of pairs of parents to select per generation
     pub number_pairs_parents: usize,
+    /// Operator selecting the parents
+    pub selection_operator: Arc<dyn SelectionOperator>,
+    /// Strategy building the next generation
+    pub replacement_strategy: ReplacementStrategy,
     /// Possible stop criterion
     pub stop_threshold: Option<f64>,
 }
@@ -22,6 +34,8 @@ impl Default for GeneticAlgorithmConfig {
             population_size: 100,
             mutation_rate: 0.1,
             number_pairs_parents: 2,
+            selection_operator: Arc::new(TruncationSelection),
+            replacement_strategy: ReplacementStrategy::default(),
             stop_threshold: None,
         }
     }
@@ -35,23 +49,50 @@ impl GeneticAlgorithmConfig {
         number_pairs_parents: usize,
         stop_threshold: Option<f64>,
     ) -> Result<Self, AlgorithmError> {
-        // Validate the data
-        if !(0.0..=1.0).contains(&mutation_rate) {
+        let config = GeneticAlgorithmConfig {
+            number_generations,
+            population_size,
+            mutation_rate,
+            number_pairs_parents,
+            selection_operator: Arc::new(TruncationSelection),
+            replacement_strategy: ReplacementStrategy::default(),
+            stop_threshold,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Set the replacement strategy, checking that it is feasible with this configuration
+    pub fn with_replacement_strategy(
+        self,
+        replacement_strategy: ReplacementStrategy,
+    ) -> Result<Self, AlgorithmError> {
+        let config = GeneticAlgorithmConfig {
+            replacement_strategy,
+            ..self
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Number of offsprings generated at each generation
+    pub fn number_offsprings(&self) -> usize {
+        2 * self.number_pairs_parents
+    }
+
+    /// Validate the data, including the feasibility of the replacement strategy
+    pub fn validate(&self) -> Result<(), AlgorithmError> {
+        if !(0.0..=1.0).contains(&self.mutation_rate) {
             return Err(AlgorithmError::ConfigurationError(
                 "the mutation rate should be between 0 and 1.",
             ));
         }
-        if 2 * number_pairs_parents > population_size {
+        if 2 * self.number_pairs_parents > self.population_size {
             return Err(AlgorithmError::ConfigurationError(
                 "the population of size should be higher than the number of parents selected at each generation",
             ));
         }
-        Ok(GeneticAlgorithmConfig {
-            number_generations,
-            population_size,
-            mutation_rate,
-            number_pairs_parents,
-            stop_threshold,
-        })
+        self.replacement_strategy
+            .check_feasibility(self.population_size, self.number_offsprings())
     }
 }
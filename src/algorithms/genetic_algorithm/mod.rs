@@ -1,2 +1,4 @@
 pub mod algorithm;
 pub mod config;
+pub mod replacement;
+pub mod selection;
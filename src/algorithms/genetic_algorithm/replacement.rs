@@ -0,0 +1,63 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Strategy deciding which individuals survive to the next generation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ReplacementStrategy {
+    /// (μ+λ): parents and offsprings compete, the best individuals survive
+    #[default]
+    MuPlusLambda,
+    /// Full generational replacement: the offsprings replace the population, except for the
+    /// `elitism` best parents which are kept. Requires enough offsprings to fill the population
+    /// with the elites.
+    Generational { elitism: usize },
+    /// (μ,λ): the best offsprings replace the whole population, requires at least as many
+    /// offsprings as the population size
+    MuCommaLambda,
+    /// Steady-state: the offsprings replace the worst individuals of the population
+    SteadyStateReplaceWorst,
+    /// Age-based: the offsprings replace the oldest individuals of the population
+    AgeBased,
+}
+
+impl ReplacementStrategy {
+    /// Check that the strategy can build a population of the given size from the offsprings
+    /// generated at each generation
+    pub fn check_feasibility(
+        &self,
+        population_size: usize,
+        number_offsprings: usize,
+    ) -> Result<(), AlgorithmError> {
+        match *self {
+            ReplacementStrategy::MuPlusLambda => Ok(()),
+            ReplacementStrategy::Generational { elitism } => {
+                if elitism > population_size {
+                    return Err(AlgorithmError::ConfigurationError(
+                        "the number of elites should not exceed the population size.",
+                    ));
+                }
+                if number_offsprings + elitism < population_size {
+                    return Err(AlgorithmError::ConfigurationError(
+                        "not enough offsprings to replace the population.",
+                    ));
+                }
+                Ok(())
+            }
+            ReplacementStrategy::MuCommaLambda => {
+                if number_offsprings < population_size {
+                    return Err(AlgorithmError::ConfigurationError(
+                        "not enough offsprings to replace the population.",
+                    ));
+                }
+                Ok(())
+            }
+            ReplacementStrategy::SteadyStateReplaceWorst | ReplacementStrategy::AgeBased => {
+                if number_offsprings > population_size {
+                    return Err(AlgorithmError::ConfigurationError(
+                        "the offsprings should not outnumber the population they replace.",
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+}
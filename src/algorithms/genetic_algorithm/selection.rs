@@ -0,0 +1,240 @@
+use std::fmt::Debug;
+
+use rand::{Rng, RngCore, seq::SliceRandom};
+
+use crate::algorithms::errors::AlgorithmError;
+
+/// Trait for the operators selecting the parents of the next offsprings
+pub trait SelectionOperator: Debug + Send + Sync {
+    /// Select `number_selected` parents given the objectives of the population, which must be
+    /// minimized. Selected indices may be repeated, consecutive indices are paired together.
+    fn select(
+        &self,
+        objectives: &[f64],
+        number_selected: usize,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<usize>, AlgorithmError>;
+}
+
+/// Turn objectives to be minimized into non-negative weights, uniform if all objectives are equal
+fn minimization_weights(objectives: &[f64]) -> Vec<f64> {
+    let worst = objectives.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let weights: Vec<f64> = objectives.iter().map(|x| worst - x).collect();
+    if weights.iter().sum::<f64>() > 0.0 {
+        weights
+    } else {
+        vec![1.0; objectives.len()]
+    }
+}
+
+/// Indices of the population sorted with the best objective first
+fn sorted_indices(objectives: &[f64]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..objectives.len()).collect();
+    indices.sort_by(|&a, &b| {
+        objectives[a]
+            .partial_cmp(&objectives[b])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    indices
+}
+
+/// Select indices with probabilities proportional to the weights, spinning the wheel once per
+/// selected individual
+fn spin_wheel(weights: &[f64], number_selected: usize, rng: &mut dyn RngCore) -> Vec<usize> {
+    let total: f64 = weights.iter().sum();
+    (0..number_selected)
+        .map(|_| {
+            let mut pointer = rng.random::<f64>() * total;
+            for (index, weight) in weights.iter().enumerate() {
+                if pointer < *weight {
+                    return index;
+                }
+                pointer -= weight;
+            }
+            weights.len() - 1
+        })
+        .collect()
+}
+
+/// Check that there are individuals to select from
+fn check_population(objectives: &[f64]) -> Result<(), AlgorithmError> {
+    if objectives.is_empty() {
+        return Err(AlgorithmError::ExecutionError(
+            "cannot select parents from an empty population",
+        ));
+    }
+    Ok(())
+}
+
+/// Top-k selection: the best individuals are paired in order
+#[derive(Debug, Clone, PartialEq)]
+pub struct TruncationSelection;
+
+impl SelectionOperator for TruncationSelection {
+    fn select(
+        &self,
+        objectives: &[f64],
+        number_selected: usize,
+        _rng: &mut dyn RngCore,
+    ) -> Result<Vec<usize>, AlgorithmError> {
+        if number_selected > objectives.len() {
+            return Err(AlgorithmError::ExecutionError(
+                "not enough individuals for truncation selection",
+            ));
+        }
+        let mut indices = sorted_indices(objectives);
+        indices.truncate(number_selected);
+        Ok(indices)
+    }
+}
+
+/// Tournament selection: the best of a few random individuals is selected
+#[derive(Debug, Clone, PartialEq)]
+pub struct TournamentSelection {
+    /// Number of individuals taking part in each tournament
+    pub tournament_size: usize,
+}
+
+impl TournamentSelection {
+    pub fn new(tournament_size: usize) -> Result<Self, AlgorithmError> {
+        if tournament_size == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the tournament size should be at least 1.",
+            ));
+        }
+        Ok(TournamentSelection { tournament_size })
+    }
+}
+
+impl SelectionOperator for TournamentSelection {
+    fn select(
+        &self,
+        objectives: &[f64],
+        number_selected: usize,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<usize>, AlgorithmError> {
+        check_population(objectives)?;
+        let selected = (0..number_selected)
+            .map(|_| {
+                (0..self.tournament_size)
+                    .map(|_| rng.random_range(0..objectives.len()))
+                    .min_by(|&a, &b| {
+                        objectives[a]
+                            .partial_cmp(&objectives[b])
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .unwrap_or(0)
+            })
+            .collect();
+        Ok(selected)
+    }
+}
+
+/// Roulette-wheel selection: individuals are selected proportionally to their distance to the
+/// worst objective of the population
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouletteWheelSelection;
+
+impl SelectionOperator for RouletteWheelSelection {
+    fn select(
+        &self,
+        objectives: &[f64],
+        number_selected: usize,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<usize>, AlgorithmError> {
+        check_population(objectives)?;
+        let weights = minimization_weights(objectives);
+        Ok(spin_wheel(&weights, number_selected, rng))
+    }
+}
+
+/// Linear rank selection: the probability of selection decreases linearly with the rank
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRankSelection {
+    /// Expected number of selections of the best individual, between 1 and 2
+    pub selection_pressure: f64,
+}
+
+impl LinearRankSelection {
+    pub fn new(selection_pressure: f64) -> Result<Self, AlgorithmError> {
+        if !(1.0..=2.0).contains(&selection_pressure) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the selection pressure should be between 1 and 2.",
+            ));
+        }
+        Ok(LinearRankSelection { selection_pressure })
+    }
+}
+
+impl SelectionOperator for LinearRankSelection {
+    fn select(
+        &self,
+        objectives: &[f64],
+        number_selected: usize,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<usize>, AlgorithmError> {
+        check_population(objectives)?;
+        let size = objectives.len();
+        let mut weights = vec![1.0; size];
+        if size > 1 {
+            let pressure = self.selection_pressure;
+            for (rank, index) in sorted_indices(objectives).into_iter().enumerate() {
+                let reversed_rank = (size - 1 - rank) as f64;
+                weights[index] =
+                    2.0 - pressure + 2.0 * (pressure - 1.0) * reversed_rank / (size - 1) as f64;
+            }
+        }
+        Ok(spin_wheel(&weights, number_selected, rng))
+    }
+}
+
+/// Stochastic universal sampling: a single spin of a wheel with equally spaced pointers
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticUniversalSampling;
+
+impl SelectionOperator for StochasticUniversalSampling {
+    fn select(
+        &self,
+        objectives: &[f64],
+        number_selected: usize,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<usize>, AlgorithmError> {
+        check_population(objectives)?;
+        let weights = minimization_weights(objectives);
+        let total: f64 = weights.iter().sum();
+        let spacing = total / number_selected.max(1) as f64;
+        let start = rng.random::<f64>() * spacing;
+
+        let mut selected = Vec::with_capacity(number_selected);
+        let mut index = 0;
+        let mut cumulative_weight = weights[0];
+        for pointer in (0..number_selected).map(|i| start + i as f64 * spacing) {
+            while cumulative_weight <= pointer && index < weights.len() - 1 {
+                index += 1;
+                cumulative_weight += weights[index];
+            }
+            selected.push(index);
+        }
+        // Avoid pairing parents according to their rank
+        selected.shuffle(rng);
+        Ok(selected)
+    }
+}
+
+/// Uniform random selection
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomSelection;
+
+impl SelectionOperator for RandomSelection {
+    fn select(
+        &self,
+        objectives: &[f64],
+        number_selected: usize,
+        rng: &mut dyn RngCore,
+    ) -> Result<Vec<usize>, AlgorithmError> {
+        check_population(objectives)?;
+        Ok((0..number_selected)
+            .map(|_| rng.random_range(0..objectives.len()))
+            .collect())
+    }
+}
@@ -0,0 +1,204 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    ant_colony::algorithm::ConstructionGraph,
+    errors::AlgorithmError,
+    grasp::config::{GraspConfig, ReactiveAlpha, RestrictedCandidateList},
+    hill_climbing::algorithm::HillClimbingAlgorithm,
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+};
+
+/// Statistics of the solutions built with each alpha of the reactive GRASP
+struct AlphaStatistics {
+    /// Probability of drawing each alpha
+    probabilities: Vec<f64>,
+    /// Sum of the objectives obtained with each alpha
+    objective_sums: Vec<f64>,
+    /// Number of uses of each alpha
+    uses: Vec<usize>,
+}
+
+impl AlphaStatistics {
+    fn new(number_alphas: usize) -> Self {
+        AlphaStatistics {
+            probabilities: vec![1.0 / number_alphas as f64; number_alphas],
+            objective_sums: vec![0.0; number_alphas],
+            uses: vec![0; number_alphas],
+        }
+    }
+
+    /// Draw the index of an alpha
+    fn draw(&self, rng: &mut impl Rng) -> usize {
+        let mut pointer = rng.random::<f64>();
+        for (index, probability) in self.probabilities.iter().enumerate() {
+            if pointer < *probability {
+                return index;
+            }
+            pointer -= probability;
+        }
+        self.probabilities.len() - 1
+    }
+
+    /// Favor the alphas whose average objective is close to the best objective found. The gap
+    /// to the best objective is divided by the largest gap, so that the probabilities do not
+    /// depend on the scale of the objective function.
+    fn update(&mut self, best_objective: f64, amplification: f64) {
+        let averages: Vec<Option<f64>> = self
+            .objective_sums
+            .iter()
+            .zip(self.uses.iter())
+            .map(|(sum, &uses)| (uses > 0).then(|| sum / uses as f64))
+            .collect();
+        let largest_gap = averages
+            .iter()
+            .flatten()
+            .map(|average| average - best_objective)
+            .fold(0.0, f64::max);
+        let qualities: Vec<f64> = averages
+            .iter()
+            .map(|average| match average {
+                Some(average) if largest_gap > 0.0 => {
+                    let gap = (average - best_objective) / largest_gap;
+                    (1.0 / (1.0 + gap)).powf(amplification)
+                }
+                _ => 1.0,
+            })
+            .collect();
+        let total: f64 = qualities.iter().sum();
+        self.probabilities = qualities.iter().map(|quality| quality / total).collect();
+    }
+}
+
+/// Main algorithm implementation for the greedy randomized adaptive search procedure
+pub struct Grasp {
+    /// Configuration object for the algorithm
+    pub config: GraspConfig,
+}
+
+impl Grasp {
+    /// Constructor to create a new Grasp struct
+    pub fn new(config: GraspConfig) -> Self {
+        Grasp { config }
+    }
+
+    /// Build a solution by adding random components of the restricted candidate list
+    fn construct_solution<T>(
+        template: &T,
+        restricted_candidate_list: RestrictedCandidateList,
+        rng: &mut impl Rng,
+    ) -> Result<T, AlgorithmError>
+    where
+        T: ConstructionGraph,
+    {
+        let mut solution = template.empty_solution();
+        loop {
+            let mut candidates: Vec<(usize, f64)> = solution
+                .candidate_components()
+                .into_iter()
+                .filter(|&component| solution.is_feasible(component))
+                .map(|component| (component, solution.heuristic(component)))
+                .collect();
+            if candidates.is_empty() {
+                break;
+            }
+
+            // Keep the best candidates according to the greedy function
+            candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            let list_size = match restricted_candidate_list {
+                RestrictedCandidateList::Value { alpha } => {
+                    let best = candidates[0].1;
+                    let worst = candidates[candidates.len() - 1].1;
+                    let threshold = best - alpha * (best - worst);
+                    candidates
+                        .iter()
+                        .take_while(|(_, greedy_value)| *greedy_value >= threshold)
+                        .count()
+                        .max(1)
+                }
+                RestrictedCandidateList::Cardinality { size } => size.min(candidates.len()),
+            };
+            let (component, _) = candidates[rng.random_range(0..list_size)];
+            solution = solution
+                .add_component(component)
+                .map_err(|_| AlgorithmError::ExecutionError("could not add the component."))?;
+        }
+        Ok(solution)
+    }
+
+    /// Find a solution with minimal objective function. The initial solution is only used as a
+    /// template for the constructions and as a fallback result.
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: ConstructionGraph + SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+        let local_search = HillClimbingAlgorithm::new(self.config.local_search.clone());
+
+        // Initialize useful mutable variables
+        let mut best_solution = initial_solution;
+        let mut iteration = 0;
+        let mut statistics = self
+            .config
+            .reactive_alpha
+            .as_ref()
+            .map(|reactive| AlphaStatistics::new(reactive.alphas.len()));
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            // Pick the restricted candidate list, drawing alpha for the reactive variant
+            let (restricted_candidate_list, alpha_index) =
+                match (&self.config.reactive_alpha, &statistics) {
+                    (Some(ReactiveAlpha { alphas, .. }), Some(statistics)) => {
+                        let index = statistics.draw(rng);
+                        (
+                            RestrictedCandidateList::Value {
+                                alpha: alphas[index],
+                            },
+                            Some(index),
+                        )
+                    }
+                    _ => (self.config.restricted_candidate_list, None),
+                };
+
+            // Build a greedy randomized solution and improve it
+            let constructed =
+                Self::construct_solution(&best_solution, restricted_candidate_list, rng)?;
+            let (local_optimum, _) =
+                local_search.climb(constructed, self.config.local_search.max_iterations, rng)?;
+            if local_optimum.objective() < best_solution.objective() {
+                best_solution = local_optimum.clone();
+            }
+
+            // Update the iteration counter and the reactive statistics
+            iteration += 1;
+            if let (Some(reactive), Some(statistics), Some(index)) = (
+                &self.config.reactive_alpha,
+                statistics.as_mut(),
+                alpha_index,
+            ) {
+                statistics.objective_sums[index] += local_optimum.objective();
+                statistics.uses[index] += 1;
+                if iteration % reactive.update_period == 0 {
+                    statistics.update(best_solution.objective(), reactive.amplification);
+                }
+            }
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
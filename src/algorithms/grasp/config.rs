@@ -0,0 +1,120 @@
+use crate::algorithms::{errors::AlgorithmError, hill_climbing::config::HillClimbingConfig};
+
+/// Rule building the restricted candidate list from the greedy values of the candidates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestrictedCandidateList {
+    /// Candidates within `alpha` of the best greedy value, relative to the range of the values.
+    /// An alpha of 0 is purely greedy, an alpha of 1 is purely random.
+    Value { alpha: f64 },
+    /// The given number of candidates with the best greedy values
+    Cardinality { size: usize },
+}
+
+/// Reactive GRASP: the alpha of each construction is drawn among a few values, with
+/// probabilities favoring the values which led to good solutions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReactiveAlpha {
+    /// Values of alpha which can be drawn
+    pub alphas: Vec<f64>,
+    /// Number of iterations between two updates of the probabilities
+    pub update_period: usize,
+    /// Exponent amplifying the differences between the probabilities
+    pub amplification: f64,
+}
+
+impl ReactiveAlpha {
+    pub fn new(
+        alphas: Vec<f64>,
+        update_period: usize,
+        amplification: f64,
+    ) -> Result<Self, AlgorithmError> {
+        if alphas.is_empty() || alphas.iter().any(|alpha| !(0.0..=1.0).contains(alpha)) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the alphas should be a non-empty list of values between 0 and 1.",
+            ));
+        }
+        if update_period == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the update period should be at least 1.",
+            ));
+        }
+        if amplification < 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the amplification should be non-negative.",
+            ));
+        }
+        Ok(ReactiveAlpha {
+            alphas,
+            update_period,
+            amplification,
+        })
+    }
+}
+
+impl Default for ReactiveAlpha {
+    fn default() -> Self {
+        ReactiveAlpha {
+            alphas: vec![0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0],
+            update_period: 20,
+            amplification: 10.0,
+        }
+    }
+}
+
+/// Configuration for the GRASP algorithm
+#[derive(Debug, Clone)]
+pub struct GraspConfig {
+    /// Maximum number of constructions followed by a local search
+    pub max_iterations: usize,
+    /// Restricted candidate list of the constructions
+    pub restricted_candidate_list: RestrictedCandidateList,
+    /// Reactive alpha adaptation, replacing the restricted candidate list if provided
+    pub reactive_alpha: Option<ReactiveAlpha>,
+    /// Local search applied to each construction, its restarts are ignored
+    pub local_search: HillClimbingConfig,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl GraspConfig {
+    pub fn new(
+        max_iterations: usize,
+        restricted_candidate_list: RestrictedCandidateList,
+        reactive_alpha: Option<ReactiveAlpha>,
+        local_search: HillClimbingConfig,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        match restricted_candidate_list {
+            RestrictedCandidateList::Value { alpha } if !(0.0..=1.0).contains(&alpha) => {
+                return Err(AlgorithmError::ConfigurationError(
+                    "the alpha should be between 0 and 1.",
+                ));
+            }
+            RestrictedCandidateList::Cardinality { size: 0 } => {
+                return Err(AlgorithmError::ConfigurationError(
+                    "the restricted candidate list should hold at least one candidate.",
+                ));
+            }
+            _ => {}
+        }
+        Ok(GraspConfig {
+            max_iterations,
+            restricted_candidate_list,
+            reactive_alpha,
+            local_search,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for GraspConfig {
+    fn default() -> Self {
+        GraspConfig {
+            max_iterations: 100,
+            restricted_candidate_list: RestrictedCandidateList::Value { alpha: 0.3 },
+            reactive_alpha: None,
+            local_search: HillClimbingConfig::default(),
+            stop_threshold: None,
+        }
+    }
+}
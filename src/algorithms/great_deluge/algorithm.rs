@@ -0,0 +1,76 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    great_deluge::config::GreatDelugeConfig,
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+};
+
+/// Main algorithm implementation for the great deluge algorithm, accepting the solutions below
+/// a water level which decreases over time
+pub struct GreatDelugeAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: GreatDelugeConfig,
+}
+
+impl GreatDelugeAlgorithm {
+    /// Constructor to create a new GreatDelugeAlgorithm struct
+    pub fn new(config: GreatDelugeConfig) -> Self {
+        GreatDelugeAlgorithm { config }
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+
+        // Initialize useful mutable variables
+        let mut level = self
+            .config
+            .initial_level
+            .unwrap_or(initial_solution.objective());
+        let mut current_solution = initial_solution;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            let new_solution = current_solution
+                .new_solution(rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+
+            // Accept the solutions which improve or stay below the water level
+            if new_solution.objective() <= current_solution.objective()
+                || new_solution.objective() <= level
+            {
+                current_solution = new_solution;
+                if current_solution.objective() < best_solution.objective() {
+                    best_solution = current_solution.clone();
+                }
+            }
+
+            // Update the water level and the iteration counter
+            level -= self.config.rain_speed;
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
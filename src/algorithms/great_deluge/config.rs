@@ -0,0 +1,46 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the great deluge algorithm
+#[derive(Debug, Clone)]
+pub struct GreatDelugeConfig {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Initial water level, the objective of the initial solution if not provided
+    pub initial_level: Option<f64>,
+    /// Decrease of the water level at each iteration
+    pub rain_speed: f64,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl GreatDelugeConfig {
+    pub fn new(
+        max_iterations: usize,
+        initial_level: Option<f64>,
+        rain_speed: f64,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if rain_speed < 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the rain speed should be non-negative.",
+            ));
+        }
+        Ok(GreatDelugeConfig {
+            max_iterations,
+            initial_level,
+            rain_speed,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for GreatDelugeConfig {
+    fn default() -> Self {
+        GreatDelugeConfig {
+            max_iterations: 1_000,
+            initial_level: None,
+            rain_speed: 0.01,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    hill_climbing::config::{HillClimbingConfig, ImprovementStrategy},
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+};
+
+/// Main algorithm implementation for the hill climbing algorithm, exploring the neighborhood
+/// defined by `SimulatedAnnealing::new_solution`
+pub struct HillClimbingAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: HillClimbingConfig,
+}
+
+impl HillClimbingAlgorithm {
+    /// Constructor to create a new HillClimbingAlgorithm struct
+    pub fn new(config: HillClimbingConfig) -> Self {
+        HillClimbingAlgorithm { config }
+    }
+
+    /// Sample the neighborhood and return the neighbor picked by the improvement strategy, if
+    /// it improves on the solution
+    fn improving_neighbor<T>(
+        &self,
+        solution: &T,
+        rng: &mut impl Rng,
+    ) -> Result<Option<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let mut best_neighbor: Option<T> = None;
+        for _ in 0..self.config.neighborhood_sample_size {
+            let neighbor = solution
+                .new_solution(rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+            let reference = best_neighbor.as_ref().unwrap_or(solution);
+            if neighbor.objective() < reference.objective() {
+                best_neighbor = Some(neighbor);
+                if self.config.improvement_strategy == ImprovementStrategy::FirstImprovement {
+                    break;
+                }
+            }
+        }
+        Ok(best_neighbor)
+    }
+
+    /// Climb from the solution until no sampled neighbor improves on it or the number of
+    /// iterations is reached. Returns the local optimum and the number of iterations used.
+    pub fn climb<T>(
+        &self,
+        initial_solution: T,
+        max_iterations: usize,
+        rng: &mut impl Rng,
+    ) -> Result<(T, usize), AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let mut current_solution = initial_solution;
+        let mut iteration = 0;
+        while iteration < max_iterations {
+            iteration += 1;
+            match self.improving_neighbor(&current_solution, rng)? {
+                Some(neighbor) => current_solution = neighbor,
+                None => break,
+            }
+        }
+        Ok((current_solution, iteration))
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+
+        // Initialize useful mutable variables
+        let mut current_solution = initial_solution;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+        let mut restart_iterations: Vec<usize> = Vec::new();
+
+        // Climb to a local optimum, then restart from a random solution
+        loop {
+            let (local_optimum, used_iterations) = self.climb(
+                current_solution,
+                self.config.max_iterations - iteration,
+                rng,
+            )?;
+            iteration += used_iterations;
+            if local_optimum.objective() < best_solution.objective() {
+                best_solution = local_optimum;
+            }
+
+            // Stopping checks
+            let reached_threshold = self
+                .config
+                .stop_threshold
+                .is_some_and(|threshold| best_solution.objective() < threshold);
+            if reached_threshold
+                || iteration >= self.config.max_iterations
+                || restart_iterations.len() >= self.config.number_restarts
+            {
+                break;
+            }
+
+            current_solution = best_solution.random_solution(rng).map_err(|_| {
+                AlgorithmError::ExecutionError("could not generate random solution.")
+            })?;
+            restart_iterations.push(iteration);
+        }
+
+        // Return the solution
+        let mut result = SimulationResult::new(best_solution, initial_time, iteration);
+        result.restart_iterations = restart_iterations;
+        Ok(result)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Rule used to pick the next solution among the sampled neighbors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImprovementStrategy {
+    /// Move to the first sampled neighbor improving on the current solution
+    FirstImprovement,
+    /// Move to the best sampled neighbor if it improves on the current solution
+    BestImprovement,
+}
+
+/// Configuration for the hill climbing algorithm
+#[derive(Debug, Clone)]
+pub struct HillClimbingConfig {
+    /// Maximum number of iterations, summed over all the restarts
+    pub max_iterations: usize,
+    /// Number of neighbors sampled at each iteration, the climb stops when none of them improves
+    pub neighborhood_sample_size: usize,
+    /// Rule used to pick the next solution
+    pub improvement_strategy: ImprovementStrategy,
+    /// Number of restarts from a random solution once a local optimum is reached
+    pub number_restarts: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl HillClimbingConfig {
+    pub fn new(
+        max_iterations: usize,
+        neighborhood_sample_size: usize,
+        improvement_strategy: ImprovementStrategy,
+        number_restarts: usize,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if neighborhood_sample_size == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the neighborhood sample size should be at least 1.",
+            ));
+        }
+        Ok(HillClimbingConfig {
+            max_iterations,
+            neighborhood_sample_size,
+            improvement_strategy,
+            number_restarts,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for HillClimbingConfig {
+    fn default() -> Self {
+        HillClimbingConfig {
+            max_iterations: 1_000,
+            neighborhood_sample_size: 20,
+            improvement_strategy: ImprovementStrategy::FirstImprovement,
+            number_restarts: 0,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::{thread, time::Instant};
+
+use rand::{Rng, SeedableRng, rngs::SmallRng, seq::index::sample};
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    genetic_algorithm::algorithm::{
+        GeneticAlgorithm, GeneticAlgorithmResult, GeneticCompatible, Population,
+    },
+    island_model::config::{EmigrantPolicy, ImmigrantPolicy, IslandModelConfig, MigrationTopology},
+};
+
+/// Population evolving on its own thread
+struct Island<T: GeneticCompatible> {
+    /// Population of the island
+    population: Population<T>,
+    /// Random number generator owned by the island
+    rng: SmallRng,
+}
+
+/// Main island model genetic algorithm, evolving several populations in parallel and
+/// periodically exchanging individuals between them
+pub struct IslandModel {
+    pub config: IslandModelConfig,
+}
+
+impl IslandModel {
+    pub fn new(config: IslandModelConfig) -> Self {
+        Self { config }
+    }
+
+    /// Islands receiving the emigrants of the given island
+    fn targets(&self, island: usize, rng: &mut impl Rng) -> Vec<usize> {
+        let number_islands = self.config.number_islands;
+        if number_islands < 2 {
+            return Vec::new();
+        }
+        match self.config.topology {
+            MigrationTopology::Ring => vec![(island + 1) % number_islands],
+            MigrationTopology::FullyConnected => (0..number_islands)
+                .filter(|&other| other != island)
+                .collect(),
+            MigrationTopology::Random => {
+                let other = rng.random_range(0..number_islands - 1);
+                vec![if other >= island { other + 1 } else { other }]
+            }
+        }
+    }
+
+    /// Copies of the individuals leaving the population
+    fn emigrants<T>(&self, population: &Population<T>, rng: &mut impl Rng) -> Vec<T>
+    where
+        T: GeneticCompatible,
+    {
+        let size = self.config.migration_size.min(population.elements.len());
+        let indices: Vec<usize> = match self.config.emigrant_policy {
+            // The population is sorted with the best individuals first
+            EmigrantPolicy::Best => (0..size).collect(),
+            EmigrantPolicy::Random => sample(rng, population.elements.len(), size).into_vec(),
+        };
+        indices
+            .into_iter()
+            .map(|index| population.elements[index].solution.clone())
+            .collect()
+    }
+
+    /// Replace individuals of the population by the immigrants
+    fn welcome<T>(&self, population: &mut Population<T>, mut immigrants: Vec<T>, rng: &mut impl Rng)
+    where
+        T: GeneticCompatible,
+    {
+        // The best resident survives, and only the best immigrants land if there are too many
+        let number_replaced = immigrants
+            .len()
+            .min(population.elements.len().saturating_sub(1));
+        immigrants.sort_by(|a, b| {
+            a.objective()
+                .partial_cmp(&b.objective())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        immigrants.truncate(number_replaced);
+        match self.config.immigrant_policy {
+            ImmigrantPolicy::ReplaceWorst => {
+                population.truncate(population.elements.len() - number_replaced);
+            }
+            ImmigrantPolicy::ReplaceRandom => {
+                // The best resident, first in the sorted population, is never replaced
+                let mut indices =
+                    sample(rng, population.elements.len() - 1, number_replaced).into_vec();
+                indices.sort_unstable_by(|a, b| b.cmp(a));
+                for index in indices {
+                    population.elements.remove(index + 1);
+                }
+            }
+        }
+        population.add_individuals(immigrants);
+        population.sort();
+    }
+
+    /// Run the algorithm, the initial elements are dealt to the islands in turn
+    pub fn execute<T>(
+        &self,
+        initial_elements: Vec<T>,
+        rng: &mut impl Rng,
+    ) -> Result<GeneticAlgorithmResult<T>, AlgorithmError>
+    where
+        T: GeneticCompatible + Send,
+    {
+        let initial_time = Instant::now();
+        let mut generation: usize = 0;
+        let number_islands = self.config.number_islands;
+        if initial_elements.len() < number_islands {
+            return Err(AlgorithmError::ExecutionError(
+                "every island should receive at least one initial element",
+            ));
+        }
+
+        // Create the islands
+        let genetic_algorithm = GeneticAlgorithm::new(self.config.genetic_algorithm.clone());
+        genetic_algorithm.config.validate()?;
+        let mut island_elements: Vec<Vec<T>> = (0..number_islands).map(|_| Vec::new()).collect();
+        for (index, element) in initial_elements.into_iter().enumerate() {
+            island_elements[index % number_islands].push(element);
+        }
+        let mut islands: Vec<Island<T>> = island_elements
+            .into_iter()
+            .map(|elements| Island {
+                population: genetic_algorithm.initial_population(elements),
+                rng: SmallRng::from_rng(&mut *rng),
+            })
+            .collect();
+        let mut best_solution = islands[0].population.best_individual()?;
+
+        // Iterate over epochs of generations separated by migrations
+        while generation < genetic_algorithm.config.number_generations {
+            // Evolve the islands in parallel
+            let number_generations = self
+                .config
+                .migration_interval
+                .min(genetic_algorithm.config.number_generations - generation);
+            let genetic_algorithm = &genetic_algorithm;
+            let island_bests = thread::scope(|scope| {
+                let handles: Vec<_> = islands
+                    .iter_mut()
+                    .map(|island| {
+                        scope.spawn(move || {
+                            // Non-elitist strategies may lose the best solution of the epoch
+                            let mut island_best = island.population.best_individual()?;
+                            for _ in 0..number_generations {
+                                genetic_algorithm
+                                    .evolve(&mut island.population, &mut island.rng)?;
+                                let best_individual = island.population.best_individual()?;
+                                if best_individual.objective() < island_best.objective() {
+                                    island_best = best_individual;
+                                }
+                            }
+                            Ok(island_best)
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle.join().map_err(|_| {
+                            AlgorithmError::ExecutionError("an island thread panicked")
+                        })?
+                    })
+                    .collect::<Result<Vec<T>, AlgorithmError>>()
+            })?;
+            generation += number_generations;
+            for island_best in island_bests {
+                if island_best.objective() < best_solution.objective() {
+                    best_solution = island_best;
+                }
+            }
+
+            // Early stopping check
+            if self.config.genetic_algorithm.stop_threshold.is_some()
+                && (best_solution.objective()
+                    < self.config.genetic_algorithm.stop_threshold.unwrap())
+            {
+                break;
+            }
+
+            // Migrate copies of individuals, all emigrants are chosen before any arrival
+            let mut immigrants: Vec<Vec<T>> = (0..number_islands).map(|_| Vec::new()).collect();
+            for (index, island) in islands.iter().enumerate() {
+                for target in self.targets(index, rng) {
+                    immigrants[target].extend(self.emigrants(&island.population, rng));
+                }
+            }
+            for (island, arrivals) in islands.iter_mut().zip(immigrants) {
+                if !arrivals.is_empty() {
+                    self.welcome(&mut island.population, arrivals, rng);
+                }
+            }
+        }
+        let result = GeneticAlgorithmResult::new(best_solution, initial_time, generation);
+        Ok(result)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::algorithms::{
+    errors::AlgorithmError, genetic_algorithm::config::GeneticAlgorithmConfig,
+};
+
+/// Islands receiving the emigrants of each island
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MigrationTopology {
+    /// Each island sends its emigrants to the next one
+    Ring,
+    /// Each island sends its emigrants to all the others
+    FullyConnected,
+    /// Each island sends its emigrants to another island drawn at random
+    Random,
+}
+
+impl MigrationTopology {
+    /// Maximum number of islands sending their emigrants to the same island
+    pub fn max_sources(&self, number_islands: usize) -> usize {
+        match self {
+            MigrationTopology::Ring => number_islands.min(2) - 1,
+            // Random targets may all pick the same island
+            MigrationTopology::FullyConnected | MigrationTopology::Random => {
+                number_islands.saturating_sub(1)
+            }
+        }
+    }
+}
+
+/// Individuals leaving an island
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmigrantPolicy {
+    /// Copies of the best individuals
+    Best,
+    /// Copies of random individuals
+    Random,
+}
+
+/// Individuals of an island replaced by the immigrants
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImmigrantPolicy {
+    /// The worst individuals are replaced
+    ReplaceWorst,
+    /// Random individuals are replaced
+    ReplaceRandom,
+}
+
+/// Configuration for the island model genetic algorithm
+#[derive(Debug, Clone)]
+pub struct IslandModelConfig {
+    /// Number of islands, each evolving its own population on a thread
+    pub number_islands: usize,
+    /// Configuration of the genetic algorithm run on every island, its number of generations
+    /// and stop threshold apply to the whole model
+    pub genetic_algorithm: GeneticAlgorithmConfig,
+    /// Number of generations between two migrations
+    pub migration_interval: usize,
+    /// Number of individuals sent by an island to each of its targets
+    pub migration_size: usize,
+    /// Islands receiving the emigrants
+    pub topology: MigrationTopology,
+    /// Individuals leaving an island
+    pub emigrant_policy: EmigrantPolicy,
+    /// Individuals replaced by the immigrants
+    pub immigrant_policy: ImmigrantPolicy,
+}
+
+impl IslandModelConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        number_islands: usize,
+        genetic_algorithm: GeneticAlgorithmConfig,
+        migration_interval: usize,
+        migration_size: usize,
+        topology: MigrationTopology,
+        emigrant_policy: EmigrantPolicy,
+        immigrant_policy: ImmigrantPolicy,
+    ) -> Result<Self, AlgorithmError> {
+        if number_islands == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "there should be at least one island.",
+            ));
+        }
+        if migration_interval == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the migration interval should be at least 1.",
+            ));
+        }
+        let config = IslandModelConfig {
+            number_islands,
+            genetic_algorithm,
+            migration_interval,
+            migration_size,
+            topology,
+            emigrant_policy,
+            immigrant_policy,
+        };
+        if config.max_immigrants() >= config.genetic_algorithm.population_size {
+            return Err(AlgorithmError::ConfigurationError(
+                "the immigrants received by an island should not replace its whole population.",
+            ));
+        }
+        Ok(config)
+    }
+
+    /// Maximum number of immigrants received by an island at each migration
+    pub fn max_immigrants(&self) -> usize {
+        self.topology.max_sources(self.number_islands) * self.migration_size
+    }
+}
+
+impl Default for IslandModelConfig {
+    fn default() -> Self {
+        IslandModelConfig {
+            number_islands: 4,
+            genetic_algorithm: GeneticAlgorithmConfig::default(),
+            migration_interval: 10,
+            migration_size: 2,
+            topology: MigrationTopology::Ring,
+            emigrant_policy: EmigrantPolicy::Best,
+            immigrant_policy: ImmigrantPolicy::ReplaceWorst,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    hill_climbing::algorithm::HillClimbingAlgorithm,
+    iterated_local_search::config::{AcceptanceCriterion, IteratedLocalSearchConfig},
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+};
+
+/// Main algorithm implementation for the iterated local search algorithm, alternating random
+/// perturbations and hill climbing
+pub struct IteratedLocalSearchAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: IteratedLocalSearchConfig,
+}
+
+impl IteratedLocalSearchAlgorithm {
+    /// Constructor to create a new IteratedLocalSearchAlgorithm struct
+    pub fn new(config: IteratedLocalSearchConfig) -> Self {
+        IteratedLocalSearchAlgorithm { config }
+    }
+
+    /// Apply successive random neighbors to escape the current local optimum
+    fn perturb<T>(&self, solution: &T, rng: &mut impl Rng) -> Result<T, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let mut perturbed = solution.clone();
+        for _ in 0..self.config.perturbation_strength {
+            perturbed = perturbed
+                .new_solution(rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+        }
+        Ok(perturbed)
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+        let local_search = HillClimbingAlgorithm::new(self.config.local_search.clone());
+        let descent_iterations = self.config.local_search.max_iterations;
+
+        // Initialize useful mutable variables
+        let (mut current_solution, _) =
+            local_search.climb(initial_solution, descent_iterations, rng)?;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+        let mut stalled_iterations = 0;
+        let mut restart_iterations: Vec<usize> = Vec::new();
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            // Perturb the current local optimum and descend to a new one
+            let perturbed = self.perturb(&current_solution, rng)?;
+            let (local_optimum, _) = local_search.climb(perturbed, descent_iterations, rng)?;
+            let is_better = local_optimum.objective() < current_solution.objective();
+            if local_optimum.objective() < best_solution.objective() {
+                best_solution = local_optimum.clone();
+                stalled_iterations = 0;
+            } else {
+                stalled_iterations += 1;
+            }
+
+            // Decide where the search continues from
+            match self.config.acceptance_criterion {
+                AcceptanceCriterion::Better => {
+                    if is_better {
+                        current_solution = local_optimum;
+                    }
+                }
+                AcceptanceCriterion::RandomWalk => current_solution = local_optimum,
+                AcceptanceCriterion::Restart { stall_iterations } => {
+                    if stalled_iterations >= stall_iterations {
+                        let restart_solution =
+                            best_solution.random_solution(rng).map_err(|_| {
+                                AlgorithmError::ExecutionError(
+                                    "could not generate random solution.",
+                                )
+                            })?;
+                        (current_solution, _) =
+                            local_search.climb(restart_solution, descent_iterations, rng)?;
+                        stalled_iterations = 0;
+                        restart_iterations.push(iteration);
+                    } else if is_better {
+                        current_solution = local_optimum;
+                    }
+                }
+            }
+
+            // Update the iteration counter
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let mut result = SimulationResult::new(best_solution, initial_time, iteration);
+        result.restart_iterations = restart_iterations;
+        Ok(result)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::algorithms::{errors::AlgorithmError, hill_climbing::config::HillClimbingConfig};
+
+/// Criterion deciding whether the search continues from the new local optimum
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AcceptanceCriterion {
+    /// Only continue from local optima improving on the current one
+    Better,
+    /// Always continue from the new local optimum
+    RandomWalk,
+    /// Continue from improving local optima, and restart from a random solution when the best
+    /// solution did not improve for the given number of iterations
+    Restart { stall_iterations: usize },
+}
+
+/// Configuration for the iterated local search algorithm
+#[derive(Debug, Clone)]
+pub struct IteratedLocalSearchConfig {
+    /// Maximum number of perturbations followed by a local search
+    pub max_iterations: usize,
+    /// Number of successive random neighbors applied by each perturbation
+    pub perturbation_strength: usize,
+    /// Local search applied after each perturbation, its restarts are ignored
+    pub local_search: HillClimbingConfig,
+    /// Criterion deciding whether the new local optimum is accepted
+    pub acceptance_criterion: AcceptanceCriterion,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl IteratedLocalSearchConfig {
+    pub fn new(
+        max_iterations: usize,
+        perturbation_strength: usize,
+        local_search: HillClimbingConfig,
+        acceptance_criterion: AcceptanceCriterion,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if perturbation_strength == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the perturbation should apply at least one move.",
+            ));
+        }
+        if let AcceptanceCriterion::Restart { stall_iterations } = acceptance_criterion
+            && stall_iterations == 0
+        {
+            return Err(AlgorithmError::ConfigurationError(
+                "the number of iterations before a restart should be at least 1.",
+            ));
+        }
+        Ok(IteratedLocalSearchConfig {
+            max_iterations,
+            perturbation_strength,
+            local_search,
+            acceptance_criterion,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for IteratedLocalSearchConfig {
+    fn default() -> Self {
+        IteratedLocalSearchConfig {
+            max_iterations: 100,
+            perturbation_strength: 3,
+            local_search: HillClimbingConfig::default(),
+            acceptance_criterion: AcceptanceCriterion::Better,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    late_acceptance_hill_climbing::config::LateAcceptanceConfig,
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+};
+
+/// Main algorithm implementation for the late acceptance hill climbing algorithm, comparing new
+/// solutions to the current objective from a fixed number of iterations ago
+pub struct LateAcceptanceHillClimbing {
+    /// Configuration object for the algorithm
+    pub config: LateAcceptanceConfig,
+}
+
+impl LateAcceptanceHillClimbing {
+    /// Constructor to create a new LateAcceptanceHillClimbing struct
+    pub fn new(config: LateAcceptanceConfig) -> Self {
+        LateAcceptanceHillClimbing { config }
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+
+        // Initialize useful mutable variables
+        let mut history = vec![initial_solution.objective(); self.config.history_length];
+        let mut current_solution = initial_solution;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            let new_solution = current_solution
+                .new_solution(rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+
+            // Accept the solutions improving on the current one or on the delayed objective
+            let slot = iteration % self.config.history_length;
+            if new_solution.objective() <= history[slot]
+                || new_solution.objective() <= current_solution.objective()
+            {
+                current_solution = new_solution;
+                if current_solution.objective() < best_solution.objective() {
+                    best_solution = current_solution.clone();
+                }
+            }
+
+            // Update the history and the iteration counter
+            history[slot] = current_solution.objective();
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
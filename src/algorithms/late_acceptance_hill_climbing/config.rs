@@ -0,0 +1,41 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the late acceptance hill climbing algorithm
+#[derive(Debug, Clone)]
+pub struct LateAcceptanceConfig {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Number of past objectives a new solution is compared to
+    pub history_length: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl LateAcceptanceConfig {
+    pub fn new(
+        max_iterations: usize,
+        history_length: usize,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if history_length == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the history should hold at least one objective.",
+            ));
+        }
+        Ok(LateAcceptanceConfig {
+            max_iterations,
+            history_length,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for LateAcceptanceConfig {
+    fn default() -> Self {
+        LateAcceptanceConfig {
+            max_iterations: 1_000,
+            history_length: 50,
+            stop_threshold: None,
+        }
+    }
+}
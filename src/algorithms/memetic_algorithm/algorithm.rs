@@ -0,0 +1,108 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    genetic_algorithm::algorithm::{
+        GeneticAlgorithm, GeneticAlgorithmResult, GeneticCompatible, Individual,
+    },
+    hill_climbing::algorithm::HillClimbingAlgorithm,
+    memetic_algorithm::config::{LearningMode, LocalImprovement, MemeticAlgorithmConfig},
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulatedAnnealingAlgorithm},
+};
+
+/// Main memetic algorithm, a genetic algorithm improving its offsprings with a local search
+pub struct MemeticAlgorithm {
+    pub config: MemeticAlgorithmConfig,
+}
+
+impl MemeticAlgorithm {
+    pub fn new(config: MemeticAlgorithmConfig) -> Self {
+        Self { config }
+    }
+
+    /// Run the local search from the solution
+    fn improve<T>(&self, solution: T, rng: &mut impl Rng) -> Result<T, AlgorithmError>
+    where
+        T: GeneticCompatible + SimulatedAnnealing,
+    {
+        match &self.config.local_improvement {
+            LocalImprovement::HillClimbing(config) => {
+                let hill_climbing = HillClimbingAlgorithm::new(config.clone());
+                let (improved, _) = hill_climbing.climb(solution, config.max_iterations, rng)?;
+                Ok(improved)
+            }
+            LocalImprovement::SimulatedAnnealing(config) => {
+                // The annealing returns the best solution visited, never worse than the initial one
+                let annealing = SimulatedAnnealingAlgorithm::new(config.clone());
+                Ok(annealing.execute(solution, rng)?.solution)
+            }
+        }
+    }
+
+    pub fn execute<T>(
+        &self,
+        initial_elements: Vec<T>,
+        rng: &mut impl Rng,
+    ) -> Result<GeneticAlgorithmResult<T>, AlgorithmError>
+    where
+        T: GeneticCompatible + SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+        let mut generation: usize = 0;
+        let genetic_algorithm = GeneticAlgorithm::new(self.config.genetic_algorithm.clone());
+        genetic_algorithm.config.validate()?;
+
+        // Create the initial population
+        let mut population = genetic_algorithm.initial_population(initial_elements);
+        let mut best_solution = population.best_individual()?;
+
+        // Iterate over generations
+        while generation < genetic_algorithm.config.number_generations {
+            // Generate offsprings and improve some of them
+            let offsprings = genetic_algorithm.breed(&mut population, rng)?;
+            let mut individuals: Vec<Individual<T>> = Vec::with_capacity(offsprings.len());
+            for offspring in offsprings {
+                if rng.random::<f64>() >= self.config.improvement_probability {
+                    individuals.push(Individual::new(offspring));
+                    continue;
+                }
+                let improved = self.improve(offspring.clone(), rng)?;
+                if improved.objective() < best_solution.objective() {
+                    best_solution = improved.clone();
+                }
+                individuals.push(match self.config.learning_mode {
+                    LearningMode::Lamarckian => Individual::new(improved),
+                    LearningMode::Baldwinian => {
+                        Individual::with_fitness(offspring, improved.objective())
+                    }
+                });
+            }
+
+            // Build the next generation from the population and the offsprings
+            population.replace(
+                individuals,
+                genetic_algorithm.config.replacement_strategy,
+                genetic_algorithm.config.population_size,
+            )?;
+            // Baldwinian individuals are ranked by their learned fitness, not their objective
+            let best_individual = population.best_individual()?;
+            if best_individual.objective() < best_solution.objective() {
+                best_solution = best_individual;
+            }
+
+            // Update the generation parameter
+            generation += 1;
+
+            // Early stopping check
+            if genetic_algorithm.config.stop_threshold.is_some()
+                && (best_solution.objective() < genetic_algorithm.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+        let result = GeneticAlgorithmResult::new(best_solution, initial_time, generation);
+        Ok(result)
+    }
+}
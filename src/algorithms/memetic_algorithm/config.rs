@@ -0,0 +1,69 @@
+use crate::algorithms::{
+    errors::AlgorithmError, genetic_algorithm::config::GeneticAlgorithmConfig,
+    hill_climbing::config::HillClimbingConfig,
+    simulated_annealing::config::SimulatedAnnealingConfig,
+};
+
+/// Local search improving the offsprings
+#[derive(Debug, Clone)]
+pub enum LocalImprovement {
+    /// Hill climbing until a local optimum, its restarts are ignored
+    HillClimbing(HillClimbingConfig),
+    /// Short simulated annealing run, keeping the best solution found
+    SimulatedAnnealing(SimulatedAnnealingConfig),
+}
+
+/// How the result of the local search is passed on
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LearningMode {
+    /// The improved solution replaces the offspring
+    Lamarckian,
+    /// The offspring is kept, but evaluated with the objective of the improved solution
+    Baldwinian,
+}
+
+/// Configuration struct for the memetic algorithm
+#[derive(Debug, Clone)]
+pub struct MemeticAlgorithmConfig {
+    /// Configuration of the underlying genetic algorithm
+    pub genetic_algorithm: GeneticAlgorithmConfig,
+    /// Local search improving the offsprings
+    pub local_improvement: LocalImprovement,
+    /// How the result of the local search is passed on
+    pub learning_mode: LearningMode,
+    /// Probability for each offspring to be improved
+    pub improvement_probability: f64,
+}
+
+impl Default for MemeticAlgorithmConfig {
+    fn default() -> Self {
+        Self {
+            genetic_algorithm: GeneticAlgorithmConfig::default(),
+            local_improvement: LocalImprovement::HillClimbing(HillClimbingConfig::default()),
+            learning_mode: LearningMode::Lamarckian,
+            improvement_probability: 1.0,
+        }
+    }
+}
+
+impl MemeticAlgorithmConfig {
+    pub fn new(
+        genetic_algorithm: GeneticAlgorithmConfig,
+        local_improvement: LocalImprovement,
+        learning_mode: LearningMode,
+        improvement_probability: f64,
+    ) -> Result<Self, AlgorithmError> {
+        // Validate the data
+        if !(0.0..=1.0).contains(&improvement_probability) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the improvement probability should be between 0 and 1.",
+            ));
+        }
+        Ok(MemeticAlgorithmConfig {
+            genetic_algorithm,
+            local_improvement,
+            learning_mode,
+            improvement_probability,
+        })
+    }
+}
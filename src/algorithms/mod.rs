@@ -1,12 +1,125 @@
+mod adaptive_large_neighborhood_search;
+mod ant_colony;
+mod cma_es;
+mod cross_entropy_method;
+mod differential_evolution;
 mod errors;
+mod estimation_of_distribution;
+mod exhaustive_search;
 mod genetic_algorithm;
+mod grasp;
+mod great_deluge;
+mod hill_climbing;
+mod island_model;
+mod iterated_local_search;
+mod late_acceptance_hill_climbing;
+mod memetic_algorithm;
+mod moead;
+mod nsga2;
+mod parallel_tempering;
+mod pareto;
+mod particle_swarm;
+mod random_search;
+mod record_to_record_travel;
 mod simulated_annealing;
+mod spea2;
+mod step_counting_hill_climbing;
+mod tabu_search;
+mod threshold_accepting;
+mod variable_neighborhood_search;
 
+pub use crate::algorithms::adaptive_large_neighborhood_search::{
+    algorithm::AdaptiveLargeNeighborhoodSearch, config::AdaptiveLargeNeighborhoodSearchConfig,
+    operators::DestroyOperator, operators::RepairOperator,
+};
+pub use crate::algorithms::ant_colony::{
+    algorithm::AntColonyAlgorithm, algorithm::ConstructionGraph, config::AntColonyConfig,
+    config::AntColonyVariant,
+};
+pub use crate::algorithms::cma_es::{
+    algorithm::CmaEsAlgorithm, config::CmaEsConfig, config::CmaEsRestart,
+};
+pub use crate::algorithms::cross_entropy_method::{
+    algorithm::CrossEntropyMethod, config::CrossEntropyMethodConfig,
+};
+pub use crate::algorithms::differential_evolution::{
+    algorithm::DifferentialEvolution, config::DifferentialEvolutionConfig,
+    config::DifferentialEvolutionStrategy, config::ParameterAdaptation,
+};
 pub use crate::algorithms::errors::AlgorithmError;
+pub use crate::algorithms::estimation_of_distribution::{
+    algorithm::CompactGeneticAlgorithm, algorithm::Pbil, algorithm::Umda,
+    config::CompactGeneticAlgorithmConfig, config::PbilConfig, config::UmdaConfig,
+};
+pub use crate::algorithms::exhaustive_search::{
+    algorithm::ExhaustiveSearch, config::ExhaustiveSearchConfig,
+};
 pub use crate::algorithms::genetic_algorithm::{
     algorithm::GeneticAlgorithm, algorithm::GeneticCompatible, config::GeneticAlgorithmConfig,
+    replacement::ReplacementStrategy, selection::LinearRankSelection, selection::RandomSelection,
+    selection::RouletteWheelSelection, selection::SelectionOperator,
+    selection::StochasticUniversalSampling, selection::TournamentSelection,
+    selection::TruncationSelection,
+};
+pub use crate::algorithms::grasp::{
+    algorithm::Grasp, config::GraspConfig, config::ReactiveAlpha, config::RestrictedCandidateList,
+};
+pub use crate::algorithms::great_deluge::{
+    algorithm::GreatDelugeAlgorithm, config::GreatDelugeConfig,
+};
+pub use crate::algorithms::hill_climbing::{
+    algorithm::HillClimbingAlgorithm, config::HillClimbingConfig, config::ImprovementStrategy,
+};
+pub use crate::algorithms::island_model::{
+    algorithm::IslandModel, config::EmigrantPolicy, config::ImmigrantPolicy,
+    config::IslandModelConfig, config::MigrationTopology,
+};
+pub use crate::algorithms::iterated_local_search::{
+    algorithm::IteratedLocalSearchAlgorithm, config::AcceptanceCriterion,
+    config::IteratedLocalSearchConfig,
+};
+pub use crate::algorithms::late_acceptance_hill_climbing::{
+    algorithm::LateAcceptanceHillClimbing, config::LateAcceptanceConfig,
+};
+pub use crate::algorithms::memetic_algorithm::{
+    algorithm::MemeticAlgorithm, config::LearningMode, config::LocalImprovement,
+    config::MemeticAlgorithmConfig,
+};
+pub use crate::algorithms::moead::{algorithm::Moead, config::MoeadConfig, config::Scalarization};
+pub use crate::algorithms::nsga2::{algorithm::Nsga2, config::Nsga2Config};
+pub use crate::algorithms::parallel_tempering::{
+    algorithm::ParallelTemperingAlgorithm, config::ParallelTemperingConfig,
+};
+pub use crate::algorithms::pareto::{
+    ParetoArchive, ParetoFrontResult, crowding_distances, dominates, non_dominated_sort,
+};
+pub use crate::algorithms::particle_swarm::{
+    algorithm::ParticleSwarmAlgorithm, config::ParticleSwarmConfig, config::SwarmTopology,
+};
+pub use crate::algorithms::random_search::{algorithm::RandomSearch, config::RandomSearchConfig};
+pub use crate::algorithms::record_to_record_travel::{
+    algorithm::RecordToRecordTravelAlgorithm, config::RecordDeviation,
+    config::RecordToRecordTravelConfig,
 };
 pub use crate::algorithms::simulated_annealing::{
     algorithm::SimulatedAnnealing, algorithm::SimulatedAnnealingAlgorithm,
-    config::SimulatedAnnealingConfig,
+    algorithm::SimulationResult, config::ReheatStrategy, config::RestartStrategy,
+    config::SimulatedAnnealingConfig, config::TemperatureCalibration, cooling::AdaptiveCooling,
+    cooling::AnnealingState, cooling::CoolingSchedule, cooling::ExponentialPlateauCooling,
+    cooling::LinearCooling, cooling::LogarithmicCooling, cooling::LundyMeesCooling,
+};
+pub use crate::algorithms::spea2::{algorithm::Spea2, config::Spea2Config};
+pub use crate::algorithms::step_counting_hill_climbing::{
+    algorithm::StepCountingHillClimbing, config::StepCounting, config::StepCountingConfig,
+};
+pub use crate::algorithms::tabu_search::{
+    algorithm::TabuCompatible, algorithm::TabuSearchAlgorithm, config::AspirationCriterion,
+    config::TabuSearchConfig,
+};
+pub use crate::algorithms::threshold_accepting::{
+    algorithm::ThresholdAcceptingAlgorithm, config::ThresholdAcceptingConfig,
+};
+pub use crate::algorithms::variable_neighborhood_search::{
+    algorithm::VariableNeighborhoodCompatible, algorithm::VariableNeighborhoodSearch,
+    config::VariableNeighborhoodSearchConfig, config::VariableNeighborhoodVariant,
 };
@@ -0,0 +1,192 @@
+use std::time::Instant;
+
+use rand::{Rng, seq::SliceRandom};
+
+use crate::{
+    algorithms::{
+        AlgorithmError, GeneticCompatible,
+        moead::config::MoeadConfig,
+        pareto::{ParetoArchive, ParetoFrontResult},
+    },
+    problems::MultiObjectiveSolution,
+};
+
+/// Number of points of the simplex lattice with the given number of divisions
+fn lattice_size(divisions: usize, number_objectives: usize) -> usize {
+    // Binomial coefficient C(divisions + m - 1, m - 1), saturating on overflow
+    (1..number_objectives).fold(1usize, |size, k| size.saturating_mul(divisions + k) / k)
+}
+
+/// Append the points of the simplex lattice whose first coordinates are given, the remaining
+/// coordinates sharing the divisions left
+fn lattice_points(
+    divisions_left: usize,
+    number_objectives: usize,
+    point: &mut Vec<usize>,
+    weights: &mut Vec<Vec<f64>>,
+) {
+    let divisions = divisions_left + point.iter().sum::<usize>();
+    if point.len() + 1 == number_objectives {
+        point.push(divisions_left);
+        weights.push(
+            point
+                .iter()
+                .map(|&share| share as f64 / divisions as f64)
+                .collect(),
+        );
+        point.pop();
+        return;
+    }
+    for share in 0..=divisions_left {
+        point.push(share);
+        lattice_points(divisions_left - share, number_objectives, point, weights);
+        point.pop();
+    }
+}
+
+/// Main MOEA/D algorithm, decomposing a multi-objective problem into scalar subproblems
+pub struct Moead {
+    pub config: MoeadConfig,
+}
+
+impl Moead {
+    pub fn new(config: MoeadConfig) -> Self {
+        Self { config }
+    }
+
+    /// Weight vectors of the subproblems, a simplex-lattice design (Das and Dennis) with the
+    /// largest number of divisions whose size does not exceed the population size. The lattice
+    /// always contains the unit vectors, hence at least one vector per objective.
+    pub fn weight_vectors(&self, number_objectives: usize) -> Vec<Vec<f64>> {
+        if number_objectives < 2 {
+            return vec![vec![1.0; number_objectives]];
+        }
+        let mut divisions = 1;
+        while lattice_size(divisions + 1, number_objectives) <= self.config.population_size {
+            divisions += 1;
+        }
+        let mut weights = Vec::new();
+        let mut point = Vec::with_capacity(number_objectives);
+        lattice_points(divisions, number_objectives, &mut point, &mut weights);
+        weights
+    }
+
+    /// Indices of the closest weight vectors of each subproblem, itself included
+    fn neighborhoods(&self, weights: &[Vec<f64>]) -> Vec<Vec<usize>> {
+        weights
+            .iter()
+            .map(|weight| {
+                let distances: Vec<f64> = weights
+                    .iter()
+                    .map(|other| {
+                        weight
+                            .iter()
+                            .zip(other.iter())
+                            .map(|(a, b)| (a - b) * (a - b))
+                            .sum()
+                    })
+                    .collect();
+                let mut order: Vec<usize> = (0..weights.len()).collect();
+                order.sort_by(|&a, &b| {
+                    distances[a]
+                        .partial_cmp(&distances[b])
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                order.truncate(self.config.neighborhood_size);
+                order
+            })
+            .collect()
+    }
+
+    /// Run the algorithm, the initial elements are assigned to the subproblems in turn
+    pub fn execute<T>(
+        &self,
+        initial_elements: Vec<T>,
+        rng: &mut impl Rng,
+    ) -> Result<ParetoFrontResult<T>, AlgorithmError>
+    where
+        T: GeneticCompatible + MultiObjectiveSolution,
+    {
+        let initial_time = Instant::now();
+        let mut generation: usize = 0;
+        if initial_elements.is_empty() {
+            return Err(AlgorithmError::ExecutionError("empty population"));
+        }
+
+        // Create the subproblems
+        let number_objectives = initial_elements[0].objectives().len();
+        let weights = self.weight_vectors(number_objectives);
+        let neighborhoods = self.neighborhoods(&weights);
+        let mut population: Vec<T> = (0..weights.len())
+            .map(|i| initial_elements[i % initial_elements.len()].clone())
+            .collect();
+        let mut objectives: Vec<Vec<f64>> = population.iter().map(|e| e.objectives()).collect();
+        let mut ideal_point = vec![f64::INFINITY; number_objectives];
+        let mut archive = ParetoArchive::new(self.config.archive_capacity);
+        for (element, element_objectives) in population.iter().zip(objectives.iter()) {
+            for (z, f) in ideal_point.iter_mut().zip(element_objectives.iter()) {
+                *z = z.min(*f);
+            }
+            archive.insert(element);
+        }
+
+        // Iterate over generations
+        while generation < self.config.number_generations {
+            for neighborhood in neighborhoods.iter() {
+                // Generate an offspring from two neighbors of the subproblem
+                let first_parent =
+                    &population[neighborhood[rng.random_range(0..neighborhood.len())]];
+                let second_parent =
+                    &population[neighborhood[rng.random_range(0..neighborhood.len())]];
+                let mut offspring = first_parent
+                    .generate_children_with(second_parent, rng)
+                    .map_err(|_| AlgorithmError::ExecutionError("could not generate offsprings"))?
+                    .into_iter()
+                    .next()
+                    .ok_or(AlgorithmError::ExecutionError(
+                        "the crossover did not generate any offspring",
+                    ))?;
+                offspring
+                    .mutate(self.config.mutation_rate, rng)
+                    .map_err(|_| AlgorithmError::ExecutionError("could not mutate offspring"))?;
+                let offspring_objectives = offspring.objectives();
+                for (z, f) in ideal_point.iter_mut().zip(offspring_objectives.iter()) {
+                    *z = z.min(*f);
+                }
+
+                // Replace the neighbors whose subproblem is improved by the offspring, visited in
+                // random order so that the closest ones are not always favored
+                let mut update_order = neighborhood.clone();
+                update_order.shuffle(rng);
+                let mut replacements = 0;
+                for neighbor in update_order {
+                    if replacements >= self.config.max_replacements {
+                        break;
+                    }
+                    let scalarization = self.config.scalarization;
+                    let new_value = scalarization.evaluate(
+                        &offspring_objectives,
+                        &weights[neighbor],
+                        &ideal_point,
+                    );
+                    let old_value = scalarization.evaluate(
+                        &objectives[neighbor],
+                        &weights[neighbor],
+                        &ideal_point,
+                    );
+                    if new_value <= old_value {
+                        population[neighbor] = offspring.clone();
+                        objectives[neighbor] = offspring_objectives.clone();
+                        replacements += 1;
+                    }
+                }
+                archive.insert(&offspring);
+            }
+
+            // Update the generation parameter
+            generation += 1;
+        }
+        let result = ParetoFrontResult::new(archive.solutions(), initial_time, generation);
+        Ok(result)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Function turning the objectives into a single value for a given weight vector
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scalarization {
+    /// Weighted Tchebycheff distance to the ideal point
+    Tchebycheff,
+    /// Weighted sum of the objectives
+    WeightedSum,
+}
+
+impl Scalarization {
+    /// Scalarize the objectives given the weights and the ideal point
+    pub fn evaluate(&self, objectives: &[f64], weights: &[f64], ideal_point: &[f64]) -> f64 {
+        match self {
+            Scalarization::Tchebycheff => objectives
+                .iter()
+                .zip(weights.iter().zip(ideal_point.iter()))
+                .map(|(f, (w, z))| w.max(1e-6) * (f - z).abs())
+                .fold(f64::NEG_INFINITY, f64::max),
+            Scalarization::WeightedSum => objectives
+                .iter()
+                .zip(weights.iter())
+                .map(|(f, w)| w * f)
+                .sum(),
+        }
+    }
+}
+
+/// Configuration struct for the MOEA/D algorithm
+#[derive(Debug, Clone)]
+pub struct MoeadConfig {
+    /// Generations
+    pub number_generations: usize,
+    /// Number of subproblems, each with its own weight vector and solution
+    pub population_size: usize,
+    /// Number of closest weight vectors forming the neighborhood of a subproblem
+    pub neighborhood_size: usize,
+    /// Maximal number of neighbors replaced by each offspring
+    pub max_replacements: usize,
+    /// Mutation rate
+    pub mutation_rate: f64,
+    /// Scalarization of the objectives
+    pub scalarization: Scalarization,
+    /// Maximal size of the external Pareto archive, unbounded if not provided
+    pub archive_capacity: Option<usize>,
+}
+
+impl Default for MoeadConfig {
+    fn default() -> Self {
+        Self {
+            number_generations: 100,
+            population_size: 100,
+            neighborhood_size: 10,
+            max_replacements: 2,
+            mutation_rate: 0.1,
+            scalarization: Scalarization::Tchebycheff,
+            archive_capacity: None,
+        }
+    }
+}
+
+impl MoeadConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        number_generations: usize,
+        population_size: usize,
+        neighborhood_size: usize,
+        max_replacements: usize,
+        mutation_rate: f64,
+        scalarization: Scalarization,
+        archive_capacity: Option<usize>,
+    ) -> Result<Self, AlgorithmError> {
+        // Validate the data
+        if !(0.0..=1.0).contains(&mutation_rate) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the mutation rate should be between 0 and 1.",
+            ));
+        }
+        if neighborhood_size < 2 || neighborhood_size > population_size {
+            return Err(AlgorithmError::ConfigurationError(
+                "the neighborhood size should be between 2 and the population size.",
+            ));
+        }
+        if max_replacements == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "each offspring should be able to replace at least one neighbor.",
+            ));
+        }
+        if archive_capacity == Some(0) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the archive should be able to hold at least one solution.",
+            ));
+        }
+        Ok(MoeadConfig {
+            number_generations,
+            population_size,
+            neighborhood_size,
+            max_replacements,
+            mutation_rate,
+            scalarization,
+            archive_capacity,
+        })
+    }
+}
@@ -0,0 +1,129 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::{
+    algorithms::{
+        AlgorithmError, GeneticCompatible,
+        nsga2::config::Nsga2Config,
+        pareto::{ParetoArchive, ParetoFrontResult, crowding_distances, non_dominated_sort},
+    },
+    problems::MultiObjectiveSolution,
+};
+
+/// Rank of the front and crowding distance of each individual of a population
+fn rank_and_crowding(objectives: &[Vec<f64>]) -> (Vec<usize>, Vec<f64>) {
+    let mut ranks = vec![0; objectives.len()];
+    let mut crowding = vec![0.0; objectives.len()];
+    for (rank, front) in non_dominated_sort(objectives).into_iter().enumerate() {
+        let distances = crowding_distances(objectives, &front);
+        for (index, distance) in front.into_iter().zip(distances) {
+            ranks[index] = rank;
+            crowding[index] = distance;
+        }
+    }
+    (ranks, crowding)
+}
+
+/// Main NSGA-II algorithm to solve multi-objective optimization problems
+pub struct Nsga2 {
+    pub config: Nsga2Config,
+}
+
+impl Nsga2 {
+    pub fn new(config: Nsga2Config) -> Self {
+        Self { config }
+    }
+
+    /// Binary tournament on the rank, then on the crowding distance
+    fn tournament(ranks: &[usize], crowding: &[f64], rng: &mut impl Rng) -> usize {
+        let a = rng.random_range(0..ranks.len());
+        let b = rng.random_range(0..ranks.len());
+        if ranks[a] < ranks[b] || (ranks[a] == ranks[b] && crowding[a] >= crowding[b]) {
+            a
+        } else {
+            b
+        }
+    }
+
+    pub fn execute<T>(
+        &self,
+        initial_elements: Vec<T>,
+        rng: &mut impl Rng,
+    ) -> Result<ParetoFrontResult<T>, AlgorithmError>
+    where
+        T: GeneticCompatible + MultiObjectiveSolution,
+    {
+        let initial_time = Instant::now();
+        let mut generation: usize = 0;
+        if initial_elements.is_empty() {
+            return Err(AlgorithmError::ExecutionError("empty population"));
+        }
+
+        // Create the initial population and archive
+        let mut archive = ParetoArchive::new(self.config.archive_capacity);
+        for element in initial_elements.iter() {
+            archive.insert(element);
+        }
+        let mut population = initial_elements;
+
+        // Iterate over generations
+        while generation < self.config.number_generations {
+            // Generate offsprings from parents picked by tournaments
+            let objectives: Vec<Vec<f64>> = population.iter().map(|e| e.objectives()).collect();
+            let (ranks, crowding) = rank_and_crowding(&objectives);
+            let mut offsprings: Vec<T> = Vec::with_capacity(self.config.population_size);
+            while offsprings.len() < self.config.population_size {
+                let first_parent = &population[Self::tournament(&ranks, &crowding, rng)];
+                let second_parent = &population[Self::tournament(&ranks, &crowding, rng)];
+                let mut children = first_parent
+                    .generate_children_with(second_parent, rng)
+                    .map_err(|_| AlgorithmError::ExecutionError("could not generate offsprings"))?;
+                if children.is_empty() {
+                    return Err(AlgorithmError::ExecutionError(
+                        "the crossover did not generate any offspring",
+                    ));
+                }
+                for child in children.iter_mut() {
+                    child.mutate(self.config.mutation_rate, rng).map_err(|_| {
+                        AlgorithmError::ExecutionError("could not mutate offspring")
+                    })?;
+                    archive.insert(child);
+                }
+                offsprings.extend(children);
+            }
+            offsprings.truncate(self.config.population_size);
+
+            // Keep the best fronts of parents and offsprings, using the crowding distance to
+            // split the last front
+            population.extend(offsprings);
+            let objectives: Vec<Vec<f64>> = population.iter().map(|e| e.objectives()).collect();
+            let mut survivors: Vec<usize> = Vec::with_capacity(self.config.population_size);
+            for front in non_dominated_sort(&objectives) {
+                if survivors.len() + front.len() <= self.config.population_size {
+                    survivors.extend(front);
+                    continue;
+                }
+                let distances = crowding_distances(&objectives, &front);
+                let mut order: Vec<usize> = (0..front.len()).collect();
+                order.sort_by(|&a, &b| {
+                    distances[b]
+                        .partial_cmp(&distances[a])
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                let remaining = self.config.population_size - survivors.len();
+                survivors.extend(order.into_iter().take(remaining).map(|k| front[k]));
+                break;
+            }
+            population = survivors
+                .into_iter()
+                .map(|index| population[index].clone())
+                .collect();
+
+            // Update the generation parameter
+            generation += 1;
+        }
+        let result = ParetoFrontResult::new(archive.solutions(), initial_time, generation);
+        Ok(result)
+    }
+}
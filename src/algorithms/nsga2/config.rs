@@ -0,0 +1,57 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration struct for the NSGA-II algorithm
+#[derive(Debug, Clone)]
+pub struct Nsga2Config {
+    /// Generations
+    pub number_generations: usize,
+    /// Population size, also the number of offsprings per generation
+    pub population_size: usize,
+    /// Mutation rate
+    pub mutation_rate: f64,
+    /// Maximal size of the Pareto archive, unbounded if not provided
+    pub archive_capacity: Option<usize>,
+}
+
+impl Default for Nsga2Config {
+    fn default() -> Self {
+        Self {
+            number_generations: 100,
+            population_size: 100,
+            mutation_rate: 0.1,
+            archive_capacity: None,
+        }
+    }
+}
+
+impl Nsga2Config {
+    pub fn new(
+        number_generations: usize,
+        population_size: usize,
+        mutation_rate: f64,
+        archive_capacity: Option<usize>,
+    ) -> Result<Self, AlgorithmError> {
+        // Validate the data
+        if !(0.0..=1.0).contains(&mutation_rate) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the mutation rate should be between 0 and 1.",
+            ));
+        }
+        if population_size < 2 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the population should contain at least two individuals.",
+            ));
+        }
+        if archive_capacity == Some(0) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the archive should be able to hold at least one solution.",
+            ));
+        }
+        Ok(Nsga2Config {
+            number_generations,
+            population_size,
+            mutation_rate,
+            archive_capacity,
+        })
+    }
+}
@@ -0,0 +1,138 @@
+use std::{thread, time::Instant};
+
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    parallel_tempering::config::ParallelTemperingConfig,
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+};
+
+/// Markov chain sampled at a fixed temperature
+struct Chain<T> {
+    /// Current state of the chain
+    current_solution: T,
+    /// Best state visited by the chain
+    best_solution: T,
+    /// Temperature of the chain
+    temperature: f64,
+    /// Random number generator owned by the chain
+    rng: SmallRng,
+}
+
+impl<T: SimulatedAnnealing> Chain<T> {
+    /// Run Metropolis steps at the temperature of the chain
+    fn run(&mut self, number_steps: usize) -> Result<(), AlgorithmError> {
+        for _ in 0..number_steps {
+            let new_solution = self
+                .current_solution
+                .new_solution(&mut self.rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+            let delta_objective = new_solution.objective() - self.current_solution.objective();
+            if (-delta_objective / self.temperature).exp() > self.rng.random() {
+                self.current_solution = new_solution;
+                if self.current_solution.objective() < self.best_solution.objective() {
+                    self.best_solution = self.current_solution.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Main algorithm implementation for parallel tempering, where chains at different temperatures
+/// run on worker threads and periodically exchange their states
+pub struct ParallelTemperingAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: ParallelTemperingConfig,
+}
+
+impl ParallelTemperingAlgorithm {
+    /// Constructor to create a new ParallelTemperingAlgorithm struct
+    pub fn new(config: ParallelTemperingConfig) -> Self {
+        ParallelTemperingAlgorithm { config }
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing + Send,
+    {
+        let initial_time = Instant::now();
+
+        // Every chain starts from the initial solution with its own random number generator
+        let mut chains: Vec<Chain<T>> = self
+            .config
+            .temperatures()
+            .into_iter()
+            .map(|temperature| Chain {
+                current_solution: initial_solution.clone(),
+                best_solution: initial_solution.clone(),
+                temperature,
+                rng: SmallRng::from_rng(&mut *rng),
+            })
+            .collect();
+        let mut best_solution = initial_solution;
+        let mut iteration = 0;
+        let mut swap_round = 0;
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            // Run the chains in parallel until the next swap attempts
+            let number_steps = self
+                .config
+                .swap_interval
+                .min(self.config.max_iterations - iteration);
+            thread::scope(|scope| {
+                let handles: Vec<_> = chains
+                    .iter_mut()
+                    .map(|chain| scope.spawn(move || chain.run(number_steps)))
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle.join().map_err(|_| {
+                            AlgorithmError::ExecutionError("a chain thread panicked.")
+                        })?
+                    })
+                    .collect::<Result<Vec<()>, AlgorithmError>>()
+            })?;
+            iteration += number_steps;
+            for chain in chains.iter() {
+                if chain.best_solution.objective() < best_solution.objective() {
+                    best_solution = chain.best_solution.clone();
+                }
+            }
+
+            // Attempt swaps between neighboring temperatures, alternating even and odd pairs
+            for index in (swap_round % 2..chains.len().saturating_sub(1)).step_by(2) {
+                let (colder, hotter) = (&chains[index], &chains[index + 1]);
+                let exponent = (1.0 / colder.temperature - 1.0 / hotter.temperature)
+                    * (colder.current_solution.objective() - hotter.current_solution.objective());
+                if exponent.exp() > rng.random() {
+                    let (left, right) = chains.split_at_mut(index + 1);
+                    std::mem::swap(
+                        &mut left[index].current_solution,
+                        &mut right[0].current_solution,
+                    );
+                }
+            }
+            swap_round += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the parallel tempering algorithm
+#[derive(Debug, Clone)]
+pub struct ParallelTemperingConfig {
+    /// Maximum number of iterations of each chain
+    pub max_iterations: usize,
+    /// Number of chains, each running on its own thread
+    pub number_chains: usize,
+    /// Temperature of the coldest chain
+    pub minimal_temperature: f64,
+    /// Temperature of the hottest chain, the ladder in between is geometric
+    pub maximal_temperature: f64,
+    /// Number of iterations of the chains between two rounds of swap attempts
+    pub swap_interval: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl ParallelTemperingConfig {
+    pub fn new(
+        max_iterations: usize,
+        number_chains: usize,
+        minimal_temperature: f64,
+        maximal_temperature: f64,
+        swap_interval: usize,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if number_chains == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "there should be at least one chain.",
+            ));
+        }
+        if minimal_temperature <= 0.0 || maximal_temperature < minimal_temperature {
+            return Err(AlgorithmError::ConfigurationError(
+                "the temperatures should be positive, and the maximal one above the minimal one.",
+            ));
+        }
+        if swap_interval == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the swap interval should be at least 1.",
+            ));
+        }
+        Ok(ParallelTemperingConfig {
+            max_iterations,
+            number_chains,
+            minimal_temperature,
+            maximal_temperature,
+            swap_interval,
+            stop_threshold,
+        })
+    }
+
+    /// Geometric ladder of temperatures, coldest first
+    pub fn temperatures(&self) -> Vec<f64> {
+        if self.number_chains == 1 {
+            return vec![self.minimal_temperature];
+        }
+        let ratio = (self.maximal_temperature / self.minimal_temperature)
+            .powf(1.0 / (self.number_chains - 1) as f64);
+        (0..self.number_chains)
+            .map(|index| self.minimal_temperature * ratio.powi(index as i32))
+            .collect()
+    }
+}
+
+impl Default for ParallelTemperingConfig {
+    fn default() -> Self {
+        ParallelTemperingConfig {
+            max_iterations: 1_000,
+            number_chains: 4,
+            minimal_temperature: 0.1,
+            maximal_temperature: 10.0,
+            swap_interval: 50,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::time::{Duration, Instant};
+
+use crate::problems::MultiObjectiveSolution;
+
+/// Check whether the first objective vector Pareto-dominates the second one (minimization)
+pub fn dominates(first: &[f64], second: &[f64]) -> bool {
+    let mut strictly_better = false;
+    for (a, b) in first.iter().zip(second.iter()) {
+        if a > b {
+            return false;
+        }
+        if a < b {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+/// Fast non-dominated sorting: indices of the successive Pareto fronts, best front first
+pub fn non_dominated_sort(objectives: &[Vec<f64>]) -> Vec<Vec<usize>> {
+    let size = objectives.len();
+    let mut dominated_by: Vec<Vec<usize>> = vec![Vec::new(); size];
+    let mut domination_count = vec![0; size];
+    for p in 0..size {
+        for q in p + 1..size {
+            if dominates(&objectives[p], &objectives[q]) {
+                dominated_by[p].push(q);
+                domination_count[q] += 1;
+            } else if dominates(&objectives[q], &objectives[p]) {
+                dominated_by[q].push(p);
+                domination_count[p] += 1;
+            }
+        }
+    }
+
+    let mut fronts: Vec<Vec<usize>> = Vec::new();
+    let mut current: Vec<usize> = (0..size).filter(|&p| domination_count[p] == 0).collect();
+    while !current.is_empty() {
+        let mut next = Vec::new();
+        for &p in current.iter() {
+            for &q in dominated_by[p].iter() {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next.push(q);
+                }
+            }
+        }
+        fronts.push(current);
+        current = next;
+    }
+    fronts
+}
+
+/// Crowding distance of each member of a front, in the order of the front.
+/// Boundary solutions of each objective get an infinite distance.
+pub fn crowding_distances(objectives: &[Vec<f64>], front: &[usize]) -> Vec<f64> {
+    let size = front.len();
+    let mut distances = vec![0.0; size];
+    if size == 0 {
+        return distances;
+    }
+    let number_objectives = objectives[front[0]].len();
+    let columns = (0..number_objectives).map(|m| {
+        front
+            .iter()
+            .map(|&index| objectives[index][m])
+            .collect::<Vec<f64>>()
+    });
+    for values in columns {
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|&a, &b| {
+            values[a]
+                .partial_cmp(&values[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let lowest = values[order[0]];
+        let highest = values[order[size - 1]];
+        distances[order[0]] = f64::INFINITY;
+        distances[order[size - 1]] = f64::INFINITY;
+        if highest - lowest <= 0.0 {
+            continue;
+        }
+        for k in 1..size.saturating_sub(1) {
+            let gap = values[order[k + 1]] - values[order[k - 1]];
+            distances[order[k]] += gap / (highest - lowest);
+        }
+    }
+    distances
+}
+
+/// Archive of the mutually non-dominated solutions found during a run
+pub struct ParetoArchive<T> {
+    /// Solutions of the archive, with their objectives
+    elements: Vec<(T, Vec<f64>)>,
+    /// Maximal number of solutions, the most crowded ones are removed first
+    capacity: Option<usize>,
+}
+
+impl<T: Clone + MultiObjectiveSolution> ParetoArchive<T> {
+    pub fn new(capacity: Option<usize>) -> Self {
+        ParetoArchive {
+            elements: Vec::new(),
+            capacity,
+        }
+    }
+    /// Insert a solution if it is not dominated by the archive, removing the solutions it dominates.
+    /// Returns whether the solution was inserted.
+    pub fn insert(&mut self, solution: &T) -> bool {
+        let objectives = solution.objectives();
+        if self
+            .elements
+            .iter()
+            .any(|(_, other)| dominates(other, &objectives) || *other == objectives)
+        {
+            return false;
+        }
+        self.elements
+            .retain(|(_, other)| !dominates(&objectives, other));
+        self.elements.push((solution.clone(), objectives));
+        if let Some(capacity) = self.capacity {
+            while self.elements.len() > capacity {
+                self.remove_most_crowded();
+            }
+        }
+        true
+    }
+    /// Remove the solution with the smallest crowding distance
+    fn remove_most_crowded(&mut self) {
+        let objectives: Vec<Vec<f64>> = self.elements.iter().map(|(_, o)| o.clone()).collect();
+        let front: Vec<usize> = (0..objectives.len()).collect();
+        let distances = crowding_distances(&objectives, &front);
+        if let Some(index) = (0..distances.len()).min_by(|&a, &b| {
+            distances[a]
+                .partial_cmp(&distances[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        }) {
+            self.elements.swap_remove(index);
+        }
+    }
+    /// Number of solutions in the archive
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+    /// Check whether the archive is empty
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+    /// Solutions of the archive
+    pub fn solutions(&self) -> Vec<T> {
+        self.elements.iter().map(|(s, _)| s.clone()).collect()
+    }
+}
+
+/// Result of a multi-objective algorithm
+pub struct ParetoFrontResult<T> {
+    /// Mutually non-dominated solutions found by the algorithm
+    pub pareto_front: Vec<T>,
+    /// Runtime of the algorithm
+    pub runtime: Duration,
+    /// Number of generations
+    pub number_generations: usize,
+}
+
+impl<T> ParetoFrontResult<T> {
+    pub fn new(pareto_front: Vec<T>, initial_time: Instant, number_generations: usize) -> Self {
+        Self {
+            pareto_front,
+            runtime: Instant::now() - initial_time,
+            number_generations,
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::{
+    algorithms::{
+        errors::AlgorithmError,
+        particle_swarm::config::{ParticleSwarmConfig, SwarmTopology},
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::RealVectorSolution,
+};
+
+/// Particle of the swarm
+struct Particle<T> {
+    /// Current solution of the particle
+    solution: T,
+    /// Current velocity of the particle
+    velocity: Vec<f64>,
+    /// Best solution visited by the particle
+    best_solution: T,
+}
+
+/// Main algorithm implementation for the particle swarm optimization algorithm
+pub struct ParticleSwarmAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: ParticleSwarmConfig,
+}
+
+impl ParticleSwarmAlgorithm {
+    /// Constructor to create a new ParticleSwarmAlgorithm struct
+    pub fn new(config: ParticleSwarmConfig) -> Self {
+        ParticleSwarmAlgorithm { config }
+    }
+
+    /// Maximal velocity along each coordinate
+    fn velocity_limits(&self, bounds: &[(f64, f64)]) -> Vec<f64> {
+        bounds
+            .iter()
+            .map(|(lower, upper)| match self.config.max_velocity {
+                Some(max_velocity) => max_velocity * (upper - lower),
+                None => f64::INFINITY,
+            })
+            .collect()
+    }
+
+    /// Index of the best informant of the particle
+    fn best_informant<T: RealVectorSolution>(
+        &self,
+        particles: &[Particle<T>],
+        index: usize,
+    ) -> usize {
+        let size = particles.len();
+        let informants: Vec<usize> = match self.config.topology {
+            SwarmTopology::Global => (0..size).collect(),
+            SwarmTopology::Ring { neighbors } => {
+                let neighbors = neighbors.min(size / 2);
+                (0..=2 * neighbors)
+                    .map(|offset| (index + size + offset - neighbors) % size)
+                    .collect()
+            }
+        };
+        informants
+            .into_iter()
+            .min_by(|&a, &b| {
+                particles[a]
+                    .best_solution
+                    .objective()
+                    .partial_cmp(&particles[b].best_solution.objective())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap_or(index)
+    }
+
+    /// Find a solution with minimal objective function, the initial solution is one of the
+    /// particles and the others are drawn uniformly within the bounds
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: RealVectorSolution,
+    {
+        let initial_time = Instant::now();
+        let bounds = initial_solution.bounds().to_vec();
+        let velocity_limits = self.velocity_limits(&bounds);
+
+        // Initialize the swarm
+        let mut particles: Vec<Particle<T>> = Vec::with_capacity(self.config.swarm_size);
+        for index in 0..self.config.swarm_size {
+            let solution = match index {
+                0 => initial_solution.clone(),
+                _ => {
+                    let position = bounds
+                        .iter()
+                        .map(|&(lower, upper)| rng.random_range(lower..=upper))
+                        .collect();
+                    initial_solution.with_position(position).map_err(|_| {
+                        AlgorithmError::ExecutionError("could not generate a particle.")
+                    })?
+                }
+            };
+            // Initial velocity towards a random point of the search space
+            let velocity = solution
+                .position()
+                .iter()
+                .zip(bounds.iter().zip(velocity_limits.iter()))
+                .map(|(x, (&(lower, upper), limit))| {
+                    ((rng.random_range(lower..=upper) - x) / 2.0).clamp(-limit, *limit)
+                })
+                .collect();
+            particles.push(Particle {
+                best_solution: solution.clone(),
+                solution,
+                velocity,
+            });
+        }
+        let mut best_solution = particles
+            .iter()
+            .map(|particle| &particle.best_solution)
+            .min_by(|a, b| {
+                a.objective()
+                    .partial_cmp(&b.objective())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .ok_or(AlgorithmError::ExecutionError("empty swarm."))?
+            .clone();
+        let mut iteration = 0;
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            for index in 0..particles.len() {
+                let informant = self.best_informant(&particles, index);
+                let social_position = particles[informant].best_solution.position().to_vec();
+                let particle = &mut particles[index];
+
+                // Update the velocity and the position, staying within the bounds
+                let mut position = particle.solution.position().to_vec();
+                for dimension in 0..position.len() {
+                    let cognitive =
+                        particle.best_solution.position()[dimension] - position[dimension];
+                    let social = social_position[dimension] - position[dimension];
+                    let limit = velocity_limits[dimension];
+                    particle.velocity[dimension] = (self.config.inertia
+                        * particle.velocity[dimension]
+                        + self.config.cognitive_coefficient * rng.random::<f64>() * cognitive
+                        + self.config.social_coefficient * rng.random::<f64>() * social)
+                        .clamp(-limit, limit);
+
+                    let (lower, upper) = bounds[dimension];
+                    position[dimension] += particle.velocity[dimension];
+                    if position[dimension] < lower || position[dimension] > upper {
+                        position[dimension] = position[dimension].clamp(lower, upper);
+                        particle.velocity[dimension] = 0.0;
+                    }
+                }
+                particle.solution = particle
+                    .solution
+                    .with_position(position)
+                    .map_err(|_| AlgorithmError::ExecutionError("could not move the particle."))?;
+
+                // Update the memories of the particle and of the swarm
+                if particle.solution.objective() < particle.best_solution.objective() {
+                    particle.best_solution = particle.solution.clone();
+                    if particle.best_solution.objective() < best_solution.objective() {
+                        best_solution = particle.best_solution.clone();
+                    }
+                }
+            }
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
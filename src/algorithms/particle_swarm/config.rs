@@ -0,0 +1,87 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Topology defining which particles share their best position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SwarmTopology {
+    /// Every particle is informed by the best particle of the swarm
+    Global,
+    /// Every particle is informed by the given number of neighbors on each side of a ring
+    Ring { neighbors: usize },
+}
+
+/// Configuration for the particle swarm optimization algorithm
+#[derive(Debug, Clone)]
+pub struct ParticleSwarmConfig {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Number of particles in the swarm
+    pub swarm_size: usize,
+    /// Inertia weight applied to the previous velocity
+    pub inertia: f64,
+    /// Attraction towards the best position found by the particle
+    pub cognitive_coefficient: f64,
+    /// Attraction towards the best position found by the neighborhood
+    pub social_coefficient: f64,
+    /// Maximal velocity along each coordinate, as a fraction of the width of its bounds
+    pub max_velocity: Option<f64>,
+    /// Topology of the swarm
+    pub topology: SwarmTopology,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl ParticleSwarmConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        max_iterations: usize,
+        swarm_size: usize,
+        inertia: f64,
+        cognitive_coefficient: f64,
+        social_coefficient: f64,
+        max_velocity: Option<f64>,
+        topology: SwarmTopology,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if swarm_size == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the swarm should contain at least one particle.",
+            ));
+        }
+        if cognitive_coefficient < 0.0 || social_coefficient < 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the cognitive and social coefficients should be positive.",
+            ));
+        }
+        if max_velocity.is_some_and(|velocity| velocity <= 0.0) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the maximal velocity should be strictly positive.",
+            ));
+        }
+        Ok(ParticleSwarmConfig {
+            max_iterations,
+            swarm_size,
+            inertia,
+            cognitive_coefficient,
+            social_coefficient,
+            max_velocity,
+            topology,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for ParticleSwarmConfig {
+    fn default() -> Self {
+        // Constriction coefficients of Clerc and Kennedy
+        ParticleSwarmConfig {
+            max_iterations: 1_000,
+            swarm_size: 30,
+            inertia: 0.7298,
+            cognitive_coefficient: 1.49618,
+            social_coefficient: 1.49618,
+            max_velocity: Some(0.2),
+            topology: SwarmTopology::Global,
+            stop_threshold: None,
+        }
+    }
+}
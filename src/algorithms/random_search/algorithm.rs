@@ -0,0 +1,69 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::{
+    algorithms::{
+        errors::AlgorithmError, random_search::config::RandomSearchConfig,
+        simulated_annealing::algorithm::SimulationResult,
+    },
+    problems::{ProblemError, ProblemSolution},
+};
+
+/// Main algorithm implementation for the random search, a baseline sampling solutions uniformly
+pub struct RandomSearch {
+    /// Configuration object for the algorithm
+    pub config: RandomSearchConfig,
+}
+
+impl RandomSearch {
+    /// Constructor to create a new RandomSearch struct
+    pub fn new(config: RandomSearchConfig) -> Self {
+        RandomSearch { config }
+    }
+
+    /// Find a solution with minimal objective function among the solutions drawn from the
+    /// generator, such as `KnapsackSolution::new_random`
+    pub fn execute<T, R, F>(
+        &self,
+        mut generator: F,
+        rng: &mut R,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: ProblemSolution,
+        R: Rng,
+        F: FnMut(&mut R) -> Result<T, ProblemError>,
+    {
+        let initial_time = Instant::now();
+        let mut sample = |rng: &mut R| {
+            generator(rng).map_err(|_| {
+                AlgorithmError::ExecutionError("could not generate a random solution.")
+            })
+        };
+
+        // The first sample is the initial best solution
+        let mut best_solution = sample(rng)?;
+        let mut iteration = 1;
+
+        while iteration < self.config.max_iterations {
+            let solution = sample(rng)?;
+            if solution.objective() < best_solution.objective() {
+                best_solution = solution;
+            }
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        Ok(SimulationResult::new(
+            best_solution,
+            initial_time,
+            iteration,
+        ))
+    }
+}
@@ -0,0 +1,33 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the random search
+#[derive(Debug, Clone)]
+pub struct RandomSearchConfig {
+    /// Maximum number of sampled solutions
+    pub max_iterations: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl RandomSearchConfig {
+    pub fn new(max_iterations: usize, stop_threshold: Option<f64>) -> Result<Self, AlgorithmError> {
+        if max_iterations == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "at least one solution should be sampled.",
+            ));
+        }
+        Ok(RandomSearchConfig {
+            max_iterations,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for RandomSearchConfig {
+    fn default() -> Self {
+        RandomSearchConfig {
+            max_iterations: 1_000,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    record_to_record_travel::config::RecordToRecordTravelConfig,
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+};
+
+/// Main algorithm implementation for the record-to-record travel algorithm, accepting the
+/// solutions within a deviation of the best objective found so far
+pub struct RecordToRecordTravelAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: RecordToRecordTravelConfig,
+}
+
+impl RecordToRecordTravelAlgorithm {
+    /// Constructor to create a new RecordToRecordTravelAlgorithm struct
+    pub fn new(config: RecordToRecordTravelConfig) -> Self {
+        RecordToRecordTravelAlgorithm { config }
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+
+        // Initialize useful mutable variables
+        let mut current_solution = initial_solution;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            let new_solution = current_solution
+                .new_solution(rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+
+            // Accept the solutions close enough to the record
+            if new_solution.objective() <= self.config.acceptance_limit(best_solution.objective()) {
+                current_solution = new_solution;
+                if current_solution.objective() < best_solution.objective() {
+                    best_solution = current_solution.clone();
+                }
+            }
+
+            // Update the iteration counter
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Allowed deviation from the record
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordDeviation {
+    /// Fixed deviation added to the record
+    Absolute(f64),
+    /// Fraction of the absolute value of the record
+    Relative(f64),
+}
+
+/// Configuration for the record-to-record travel algorithm
+#[derive(Debug, Clone)]
+pub struct RecordToRecordTravelConfig {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Allowed deviation, a solution is accepted if it is below the record plus the deviation
+    pub deviation: RecordDeviation,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl RecordToRecordTravelConfig {
+    pub fn new(
+        max_iterations: usize,
+        deviation: RecordDeviation,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        let (RecordDeviation::Absolute(value) | RecordDeviation::Relative(value)) = deviation;
+        if value < 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the deviation should be non-negative.",
+            ));
+        }
+        Ok(RecordToRecordTravelConfig {
+            max_iterations,
+            deviation,
+            stop_threshold,
+        })
+    }
+
+    /// Highest objective accepted given the record
+    pub fn acceptance_limit(&self, record: f64) -> f64 {
+        match self.deviation {
+            RecordDeviation::Absolute(deviation) => record + deviation,
+            RecordDeviation::Relative(deviation) => record + deviation * record.abs(),
+        }
+    }
+}
+
+impl Default for RecordToRecordTravelConfig {
+    fn default() -> Self {
+        RecordToRecordTravelConfig {
+            max_iterations: 1_000,
+            deviation: RecordDeviation::Relative(0.01),
+            stop_threshold: None,
+        }
+    }
+}
@@ -1,15 +1,31 @@
 use std::time::{Duration, Instant};
 
 use crate::{
-    algorithms::{errors::AlgorithmError, simulated_annealing::config::SimulatedAnnealingConfig},
+    algorithms::{
+        errors::AlgorithmError,
+        simulated_annealing::{
+            config::{RestartStrategy, SimulatedAnnealingConfig, TemperatureCalibration},
+            cooling::AnnealingState,
+        },
+    },
     problems::{ProblemError, ProblemSolution},
 };
 use rand::Rng;
 
+/// Smoothing factor of the moving average of the acceptance ratio
+const ACCEPTANCE_SMOOTHING: f64 = 0.99;
+
 /// Trait for solutions with local variations
 pub trait SimulatedAnnealing: Clone + std::fmt::Debug + ProblemSolution {
     /// Generate a new solution
     fn new_solution(&self, rng: &mut impl Rng) -> Result<Self, ProblemError>;
+
+    /// Generate a new random solution of the same problem, used for restarts
+    fn random_solution(&self, _rng: &mut impl Rng) -> Result<Self, ProblemError> {
+        Err(ProblemError::NewSolutionError(
+            "random solutions are not supported for this problem.",
+        ))
+    }
 }
 
 /// Main algorithm implementation for the simulated annealing algorithm
@@ -26,6 +42,10 @@ pub struct SimulationResult<T> {
     pub runtime: Duration,
     /// Number of iterations
     pub number_iterations: usize,
+    /// Iterations at which the search was reheated or restarted
+    pub reheat_iterations: Vec<usize>,
+    /// Iterations at which a multi-start algorithm restarted its search
+    pub restart_iterations: Vec<usize>,
 }
 
 impl<T> SimulationResult<T> {
@@ -34,8 +54,20 @@ impl<T> SimulationResult<T> {
             solution,
             runtime: Instant::now() - initial_time,
             number_iterations,
+            reheat_iterations: Vec::new(),
+            restart_iterations: Vec::new(),
         }
     }
+
+    /// Number of times the search was reheated or restarted
+    pub fn number_reheats(&self) -> usize {
+        self.reheat_iterations.len()
+    }
+
+    /// Number of times the search was restarted
+    pub fn number_restarts(&self) -> usize {
+        self.restart_iterations.len()
+    }
 }
 
 impl SimulatedAnnealingAlgorithm {
@@ -44,8 +76,77 @@ impl SimulatedAnnealingAlgorithm {
         SimulatedAnnealingAlgorithm { config }
     }
 
-    fn cooldown(&self, temperature: f64) -> f64 {
-        temperature * self.config.cooling_rate
+    fn cooldown(&self, state: &AnnealingState) -> f64 {
+        match &self.config.cooling_schedule {
+            Some(schedule) => schedule.next_temperature(state),
+            None => state.temperature * self.config.cooling_rate,
+        }
+    }
+
+    /// Estimate the initial temperature giving the target acceptance ratio of worsening moves.
+    /// Transitions are sampled along a random walk starting from the initial solution, then the
+    /// Kirkpatrick estimate is refined with the iterative method of Ben-Ameur.
+    pub fn calibrate_temperature<T>(
+        &self,
+        initial_solution: &T,
+        calibration: &TemperatureCalibration,
+        rng: &mut impl Rng,
+    ) -> Result<f64, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        // Sample the objective before and after each worsening move
+        let mut transitions: Vec<(f64, f64)> = Vec::with_capacity(calibration.number_samples);
+        let mut current_solution = initial_solution.clone();
+        for _ in 0..calibration.number_samples {
+            let new_solution = current_solution
+                .new_solution(rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+            let (before, after) = (current_solution.objective(), new_solution.objective());
+            if after > before {
+                transitions.push((before, after));
+            }
+            current_solution = new_solution;
+        }
+        // Without any worsening move, there is nothing to calibrate
+        if transitions.is_empty() {
+            return Ok(self.config.initial_temperature);
+        }
+
+        // Initial estimate from the average worsening (Kirkpatrick)
+        let target = calibration.target_acceptance_ratio;
+        let mean_delta = transitions
+            .iter()
+            .map(|(before, after)| after - before)
+            .sum::<f64>()
+            / transitions.len() as f64;
+        let mut temperature = -mean_delta / target.ln();
+
+        // Iterative refinement, objectives are shifted to avoid overflows
+        let reference = transitions
+            .iter()
+            .map(|(before, _)| *before)
+            .fold(f64::INFINITY, f64::min);
+        for _ in 0..calibration.max_refinements {
+            let (accepted, proposed) =
+                transitions
+                    .iter()
+                    .fold((0.0, 0.0), |(accepted, proposed), (before, after)| {
+                        (
+                            accepted + (-(after - reference) / temperature).exp(),
+                            proposed + (-(before - reference) / temperature).exp(),
+                        )
+                    });
+            let acceptance_ratio = accepted / proposed;
+            if (acceptance_ratio - target).abs() < calibration.tolerance
+                || !acceptance_ratio.is_normal()
+            {
+                break;
+            }
+            temperature *= acceptance_ratio.ln() / target.ln();
+        }
+
+        Ok(temperature.max(self.config.minimal_temperature))
     }
 
     /// Find a solution with minimal objective function
@@ -59,11 +160,23 @@ impl SimulatedAnnealingAlgorithm {
     {
         let initial_time = Instant::now();
 
+        // Pick the initial temperature
+        let initial_temperature = match &self.config.temperature_calibration {
+            Some(calibration) => self.calibrate_temperature(&initial_solution, calibration, rng)?,
+            None => self.config.initial_temperature,
+        };
+
         // Initialize useful mutable variables
         let mut current_solution = initial_solution;
         let mut best_solution = current_solution.clone();
         let mut iteration = 0;
-        let mut temperature = self.config.initial_temperature;
+        let mut temperature = initial_temperature;
+        let mut acceptance_ratio = 1.0;
+        // The cooling schedule starts over after each reheat
+        let mut schedule_start = 0;
+        let mut schedule_temperature = initial_temperature;
+        let mut stalled_iterations = 0;
+        let mut reheat_iterations: Vec<usize> = Vec::new();
 
         // Loop until the final criterion is reached
         while iteration < self.config.max_iterations {
@@ -74,19 +187,54 @@ impl SimulatedAnnealingAlgorithm {
             // If the new solution's value is higher than the current one, always accepts it
             // Otherwise, acccept with a probability dependent on the temperature
             let delta_objective = new_solution.objective() - current_solution.objective();
-            if (-delta_objective / temperature).exp() > rng.random() {
+            let accepted = (-delta_objective / temperature).exp() > rng.random();
+            if accepted {
                 current_solution = new_solution;
 
                 if current_solution.objective() < best_solution.objective() {
                     best_solution = current_solution.clone();
+                    stalled_iterations = 0;
                 }
             }
+            let accepted_value = if accepted { 1.0 } else { 0.0 };
+            acceptance_ratio = ACCEPTANCE_SMOOTHING * acceptance_ratio
+                + (1.0 - ACCEPTANCE_SMOOTHING) * accepted_value;
 
             // Update temperature and iteration counter
-            temperature = self
-                .cooldown(temperature)
-                .max(self.config.minimal_temperature);
+            let state = AnnealingState {
+                iteration: iteration - schedule_start,
+                temperature,
+                initial_temperature: schedule_temperature,
+                acceptance_ratio,
+            };
+            temperature = self.cooldown(&state).max(self.config.minimal_temperature);
             iteration += 1;
+            stalled_iterations += 1;
+
+            // Reheat or restart if the best solution has not improved for too long
+            if let Some(reheat) = &self.config.reheat_strategy
+                && stalled_iterations >= reheat.stall_iterations
+            {
+                if let Some(reheat_temperature) = reheat.reheat_temperature {
+                    temperature = reheat_temperature;
+                }
+                current_solution = match reheat.restart {
+                    RestartStrategy::Current => current_solution,
+                    RestartStrategy::Best => best_solution.clone(),
+                    RestartStrategy::Random => {
+                        current_solution.random_solution(rng).map_err(|_| {
+                            AlgorithmError::ExecutionError("could not generate a random solution.")
+                        })?
+                    }
+                };
+                if current_solution.objective() < best_solution.objective() {
+                    best_solution = current_solution.clone();
+                }
+                schedule_start = iteration;
+                schedule_temperature = temperature;
+                stalled_iterations = 0;
+                reheat_iterations.push(iteration);
+            }
 
             // Early stopping check
             if self.config.stop_threshold.is_some()
@@ -97,7 +245,8 @@ impl SimulatedAnnealingAlgorithm {
         }
 
         // Return the solution
-        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        let mut result = SimulationResult::new(best_solution, initial_time, iteration);
+        result.reheat_iterations = reheat_iterations;
         Ok(result)
     }
 }
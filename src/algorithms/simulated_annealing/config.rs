@@ -1,6 +1,115 @@
-use crate::algorithms::errors::AlgorithmError;
+use std::sync::Arc;
+
+use crate::algorithms::{errors::AlgorithmError, simulated_annealing::cooling::CoolingSchedule};
+
+/// Parameters of the automatic estimation of the initial temperature (Ben-Ameur method)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemperatureCalibration {
+    /// Ratio of worsening moves which should be accepted at the initial temperature
+    pub target_acceptance_ratio: f64,
+    /// Number of random moves sampled from the initial solution
+    pub number_samples: usize,
+    /// Maximum number of refinement steps of the temperature
+    pub max_refinements: usize,
+    /// Tolerance on the acceptance ratio below which the refinement stops
+    pub tolerance: f64,
+}
+
+impl TemperatureCalibration {
+    pub fn new(
+        target_acceptance_ratio: f64,
+        number_samples: usize,
+        max_refinements: usize,
+        tolerance: f64,
+    ) -> Result<Self, AlgorithmError> {
+        if target_acceptance_ratio <= 0.0 || target_acceptance_ratio >= 1.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the target acceptance ratio should be strictly between 0 and 1.",
+            ));
+        }
+        if number_samples == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "at least one move should be sampled to calibrate the temperature.",
+            ));
+        }
+        if tolerance <= 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the tolerance should be strictly positive.",
+            ));
+        }
+        Ok(TemperatureCalibration {
+            target_acceptance_ratio,
+            number_samples,
+            max_refinements,
+            tolerance,
+        })
+    }
+}
+
+impl Default for TemperatureCalibration {
+    fn default() -> Self {
+        TemperatureCalibration {
+            target_acceptance_ratio: 0.8,
+            number_samples: 100,
+            max_refinements: 100,
+            tolerance: 1e-3,
+        }
+    }
+}
+
+/// Solution from which the search continues after a reheat
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestartStrategy {
+    /// Continue from the current solution
+    Current,
+    /// Restart from the best solution found so far
+    Best,
+    /// Restart from a new random solution
+    Random,
+}
+
+/// Parameters of the reheating performed when the search stalls
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReheatStrategy {
+    /// Number of iterations without improvement of the best solution before reheating
+    pub stall_iterations: usize,
+    /// Temperature set after a reheat, the temperature is left unchanged if not provided
+    pub reheat_temperature: Option<f64>,
+    /// Solution from which the search continues after a reheat
+    pub restart: RestartStrategy,
+}
+
+impl ReheatStrategy {
+    pub fn new(
+        stall_iterations: usize,
+        reheat_temperature: Option<f64>,
+        restart: RestartStrategy,
+    ) -> Result<Self, AlgorithmError> {
+        if stall_iterations == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the number of stalled iterations before a reheat should be at least 1.",
+            ));
+        }
+        if reheat_temperature.is_some_and(|temperature| temperature < 0.0) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the reheat temperature should be larger than 0.0.",
+            ));
+        }
+        if reheat_temperature.is_none() && restart == RestartStrategy::Current {
+            return Err(AlgorithmError::ConfigurationError(
+                "a reheat should either change the temperature or restart the search.",
+            ));
+        }
+        Ok(ReheatStrategy {
+            stall_iterations,
+            reheat_temperature,
+            restart,
+        })
+    }
+}
 
 /// Configuration for the simulated annealing algorithm
+#[derive(Debug, Clone)]
 pub struct SimulatedAnnealingConfig {
     /// Maximum number of iterations
     pub max_iterations: usize,
@@ -8,8 +117,15 @@ pub struct SimulatedAnnealingConfig {
     pub initial_temperature: f64,
     /// Minimal temperature below which the temperature gets clipped
     pub minimal_temperature: f64,
-    /// Cooling rate
+    /// Estimate the initial temperature from the initial solution instead of using `initial_temperature`
+    pub temperature_calibration: Option<TemperatureCalibration>,
+    /// Cooling rate of the default geometric cooling
     pub cooling_rate: f64,
+    /// Cooling schedule replacing the geometric cooling if provided, shared between the copies
+    /// of the configuration
+    pub cooling_schedule: Option<Arc<dyn CoolingSchedule>>,
+    /// Reheating performed when the best solution stops improving
+    pub reheat_strategy: Option<ReheatStrategy>,
     /// Threshold under which the objective function should stop (if the target value is zero)
     pub stop_threshold: Option<f64>,
 }
@@ -39,7 +155,10 @@ impl SimulatedAnnealingConfig {
             max_iterations,
             initial_temperature,
             minimal_temperature,
+            temperature_calibration: None,
             cooling_rate,
+            cooling_schedule: None,
+            reheat_strategy: None,
             stop_threshold,
         })
     }
@@ -51,7 +170,10 @@ impl Default for SimulatedAnnealingConfig {
             max_iterations: 1_000,
             initial_temperature: 1.0,
             minimal_temperature: 0.0,
+            temperature_calibration: None,
             cooling_rate: 0.99,
+            cooling_schedule: None,
+            reheat_strategy: None,
             stop_threshold: None,
         }
     }
@@ -0,0 +1,157 @@
+use std::fmt::Debug;
+
+use crate::algorithms::errors::AlgorithmError;
+
+/// State of the annealing process used to compute the next temperature
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnnealingState {
+    /// Number of iterations performed since the start of the algorithm or the last reheat
+    pub iteration: usize,
+    /// Current temperature
+    pub temperature: f64,
+    /// Temperature at the start of the algorithm or after the last reheat
+    pub initial_temperature: f64,
+    /// Exponential moving average of the ratio of accepted moves
+    pub acceptance_ratio: f64,
+}
+
+/// Trait for the schedules updating the temperature after each iteration
+pub trait CoolingSchedule: Debug + Send + Sync {
+    /// Compute the temperature of the next iteration
+    fn next_temperature(&self, state: &AnnealingState) -> f64;
+}
+
+/// Linear cooling: the temperature decreases by a constant amount at each iteration
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearCooling {
+    /// Amount subtracted from the temperature at each iteration
+    pub decrement: f64,
+}
+
+impl LinearCooling {
+    pub fn new(decrement: f64) -> Result<Self, AlgorithmError> {
+        if decrement < 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the temperature decrement should be positive.",
+            ));
+        }
+        Ok(LinearCooling { decrement })
+    }
+}
+
+impl CoolingSchedule for LinearCooling {
+    fn next_temperature(&self, state: &AnnealingState) -> f64 {
+        (state.temperature - self.decrement).max(0.0)
+    }
+}
+
+/// Logarithmic cooling (Geman–Geman): `T_k = c / ln(k + 2)` with `c = T_0 ln(2)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogarithmicCooling;
+
+impl CoolingSchedule for LogarithmicCooling {
+    fn next_temperature(&self, state: &AnnealingState) -> f64 {
+        let iteration = (state.iteration + 1) as f64;
+        state.initial_temperature * 2.0_f64.ln() / (iteration + 2.0).ln()
+    }
+}
+
+/// Lundy–Mees cooling: `T_{k+1} = T_k / (1 + beta * T_k)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LundyMeesCooling {
+    /// Cooling parameter, small values cool down slowly
+    pub beta: f64,
+}
+
+impl LundyMeesCooling {
+    pub fn new(beta: f64) -> Result<Self, AlgorithmError> {
+        if beta <= 0.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the Lundy-Mees parameter should be strictly positive.",
+            ));
+        }
+        Ok(LundyMeesCooling { beta })
+    }
+}
+
+impl CoolingSchedule for LundyMeesCooling {
+    fn next_temperature(&self, state: &AnnealingState) -> f64 {
+        state.temperature / (1.0 + self.beta * state.temperature)
+    }
+}
+
+/// Exponential cooling where the temperature is kept constant on plateaus of fixed length
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentialPlateauCooling {
+    /// Cooling rate applied at the end of each plateau
+    pub cooling_rate: f64,
+    /// Number of iterations spent at each temperature
+    pub plateau_length: usize,
+}
+
+impl ExponentialPlateauCooling {
+    pub fn new(cooling_rate: f64, plateau_length: usize) -> Result<Self, AlgorithmError> {
+        if !(0.0..=1.0).contains(&cooling_rate) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the cooling rate should be between 0 and 1.",
+            ));
+        }
+        if plateau_length == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the plateau length should be at least 1.",
+            ));
+        }
+        Ok(ExponentialPlateauCooling {
+            cooling_rate,
+            plateau_length,
+        })
+    }
+}
+
+impl CoolingSchedule for ExponentialPlateauCooling {
+    fn next_temperature(&self, state: &AnnealingState) -> f64 {
+        match (state.iteration + 1) % self.plateau_length {
+            0 => state.temperature * self.cooling_rate,
+            _ => state.temperature,
+        }
+    }
+}
+
+/// Adaptive cooling: cool down while too many moves are accepted, heat up otherwise.
+/// The temperature never goes above the initial temperature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdaptiveCooling {
+    /// Acceptance ratio the schedule tries to maintain
+    pub target_acceptance_ratio: f64,
+    /// Multiplicative factor applied to the temperature at each iteration
+    pub adjustment_rate: f64,
+}
+
+impl AdaptiveCooling {
+    pub fn new(target_acceptance_ratio: f64, adjustment_rate: f64) -> Result<Self, AlgorithmError> {
+        if !(0.0..=1.0).contains(&target_acceptance_ratio) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the target acceptance ratio should be between 0 and 1.",
+            ));
+        }
+        if adjustment_rate <= 0.0 || adjustment_rate > 1.0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the adjustment rate should be in ]0, 1].",
+            ));
+        }
+        Ok(AdaptiveCooling {
+            target_acceptance_ratio,
+            adjustment_rate,
+        })
+    }
+}
+
+impl CoolingSchedule for AdaptiveCooling {
+    fn next_temperature(&self, state: &AnnealingState) -> f64 {
+        if state.acceptance_ratio > self.target_acceptance_ratio {
+            state.temperature * self.adjustment_rate
+        } else {
+            (state.temperature / self.adjustment_rate).min(state.initial_temperature)
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod algorithm;
 pub mod config;
+pub mod cooling;
@@ -0,0 +1,231 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::{
+    algorithms::{
+        AlgorithmError, GeneticCompatible,
+        pareto::{ParetoFrontResult, dominates},
+        spea2::config::Spea2Config,
+    },
+    problems::MultiObjectiveSolution,
+};
+
+/// Euclidean distance between two objective vectors
+fn distance(first: &[f64], second: &[f64]) -> f64 {
+    first
+        .iter()
+        .zip(second.iter())
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Main SPEA2 algorithm to solve multi-objective optimization problems
+pub struct Spea2 {
+    pub config: Spea2Config,
+}
+
+impl Spea2 {
+    pub fn new(config: Spea2Config) -> Self {
+        Self { config }
+    }
+
+    /// Fitness of each individual, the raw fitness from the strengths of its dominators plus its density
+    fn fitness(&self, objectives: &[Vec<f64>]) -> Vec<f64> {
+        let size = objectives.len();
+        let strengths: Vec<usize> = objectives
+            .iter()
+            .map(|first| {
+                objectives
+                    .iter()
+                    .filter(|second| dominates(first, second))
+                    .count()
+            })
+            .collect();
+        let neighbor = self
+            .config
+            .density_neighbor
+            .unwrap_or((size as f64).sqrt() as usize)
+            .clamp(1, size.saturating_sub(1).max(1));
+        objectives
+            .iter()
+            .map(|first| {
+                let raw_fitness: usize = objectives
+                    .iter()
+                    .zip(strengths.iter())
+                    .filter(|(second, _)| dominates(second, first))
+                    .map(|(_, strength)| strength)
+                    .sum();
+                let mut distances: Vec<f64> = objectives
+                    .iter()
+                    .map(|second| distance(first, second))
+                    .collect();
+                distances.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+                // The closest distance is the individual itself
+                let kth_distance = distances.get(neighbor).copied().unwrap_or(0.0);
+                raw_fitness as f64 + 1.0 / (kth_distance + 2.0)
+            })
+            .collect()
+    }
+
+    /// Iteratively remove the individual closest to the others until the archive fits
+    fn truncate(&self, objectives: &[Vec<f64>], selected: Vec<usize>) -> Vec<usize> {
+        let distances: Vec<Vec<f64>> = selected
+            .iter()
+            .map(|&first| {
+                selected
+                    .iter()
+                    .map(|&second| distance(&objectives[first], &objectives[second]))
+                    .collect()
+            })
+            .collect();
+        // Duplicates are always the closest individuals, so they are removed first
+        let mut remaining: Vec<usize> = Vec::with_capacity(selected.len());
+        for (position, row) in distances.iter().enumerate() {
+            let is_duplicate = remaining.iter().any(|&kept| row[kept] == 0.0);
+            let number_left = selected.len() - position;
+            if !is_duplicate || remaining.len() + number_left <= self.config.archive_size {
+                remaining.push(position);
+            }
+        }
+        while remaining.len() > self.config.archive_size {
+            let sorted_distances = |a: usize| -> Vec<f64> {
+                let mut row: Vec<f64> = remaining
+                    .iter()
+                    .filter(|&&b| b != a)
+                    .map(|&b| distances[a][b])
+                    .collect();
+                row.sort_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+                row
+            };
+            // Only the individuals with the smallest nearest neighbor distance are compared
+            let nearest: Vec<f64> = remaining
+                .iter()
+                .map(|&a| {
+                    remaining
+                        .iter()
+                        .filter(|&&b| b != a)
+                        .map(|&b| distances[a][b])
+                        .fold(f64::INFINITY, f64::min)
+                })
+                .collect();
+            let smallest = nearest.iter().copied().fold(f64::INFINITY, f64::min);
+            let candidates: Vec<usize> = (0..remaining.len())
+                .filter(|&position| nearest[position] == smallest)
+                .collect();
+            // Lexicographic comparison of the distances to the nearest neighbors
+            let removed = candidates
+                .iter()
+                .map(|&position| (position, sorted_distances(remaining[position])))
+                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(position, _)| position)
+                .unwrap_or(0);
+            remaining.swap_remove(removed);
+        }
+        remaining.sort_unstable();
+        remaining
+            .into_iter()
+            .map(|position| selected[position])
+            .collect()
+    }
+
+    /// Environmental selection of the next archive from the fitness of the individuals
+    fn environmental_selection(&self, objectives: &[Vec<f64>], fitness: &[f64]) -> Vec<usize> {
+        let non_dominated: Vec<usize> = (0..fitness.len()).filter(|&i| fitness[i] < 1.0).collect();
+        if non_dominated.len() > self.config.archive_size {
+            return self.truncate(objectives, non_dominated);
+        }
+        // Fill the archive with the best dominated individuals
+        let mut order: Vec<usize> = (0..fitness.len()).collect();
+        order.sort_by(|&a, &b| {
+            fitness[a]
+                .partial_cmp(&fitness[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        order.truncate(self.config.archive_size);
+        order
+    }
+
+    /// Binary tournament on the fitness
+    fn tournament(fitness: &[f64], rng: &mut impl Rng) -> usize {
+        let a = rng.random_range(0..fitness.len());
+        let b = rng.random_range(0..fitness.len());
+        if fitness[a] <= fitness[b] { a } else { b }
+    }
+
+    pub fn execute<T>(
+        &self,
+        initial_elements: Vec<T>,
+        rng: &mut impl Rng,
+    ) -> Result<ParetoFrontResult<T>, AlgorithmError>
+    where
+        T: GeneticCompatible + MultiObjectiveSolution,
+    {
+        let initial_time = Instant::now();
+        let mut generation: usize = 0;
+        if initial_elements.is_empty() {
+            return Err(AlgorithmError::ExecutionError("empty population"));
+        }
+
+        // Create the initial population with an empty archive
+        let mut population = initial_elements;
+        let mut archive: Vec<T> = Vec::with_capacity(self.config.archive_size);
+
+        loop {
+            // Select the next archive among the population and the previous archive
+            population.append(&mut archive);
+            let objectives: Vec<Vec<f64>> = population.iter().map(|e| e.objectives()).collect();
+            let fitness = self.fitness(&objectives);
+            let selected = self.environmental_selection(&objectives, &fitness);
+            let archive_fitness: Vec<f64> = selected.iter().map(|&i| fitness[i]).collect();
+            archive = selected.iter().map(|&i| population[i].clone()).collect();
+            if generation >= self.config.number_generations {
+                break;
+            }
+
+            // Generate offsprings from parents of the archive picked by tournaments
+            let mut offsprings: Vec<T> = Vec::with_capacity(self.config.population_size);
+            while offsprings.len() < self.config.population_size {
+                let first_parent = &archive[Self::tournament(&archive_fitness, rng)];
+                let second_parent = &archive[Self::tournament(&archive_fitness, rng)];
+                let mut children = first_parent
+                    .generate_children_with(second_parent, rng)
+                    .map_err(|_| AlgorithmError::ExecutionError("could not generate offsprings"))?;
+                if children.is_empty() {
+                    return Err(AlgorithmError::ExecutionError(
+                        "the crossover did not generate any offspring",
+                    ));
+                }
+                for child in children.iter_mut() {
+                    child.mutate(self.config.mutation_rate, rng).map_err(|_| {
+                        AlgorithmError::ExecutionError("could not mutate offspring")
+                    })?;
+                }
+                offsprings.extend(children);
+            }
+            offsprings.truncate(self.config.population_size);
+            population = offsprings;
+
+            // Update the generation parameter
+            generation += 1;
+        }
+
+        // The front is made of the non-dominated solutions of the final archive
+        let objectives: Vec<Vec<f64>> = archive.iter().map(|e| e.objectives()).collect();
+        let mut pareto_front: Vec<T> = Vec::new();
+        for (index, element) in archive.iter().enumerate() {
+            let is_dominated = objectives
+                .iter()
+                .any(|other| dominates(other, &objectives[index]));
+            let is_duplicate = pareto_front
+                .iter()
+                .any(|kept| kept.objectives() == objectives[index]);
+            if !is_dominated && !is_duplicate {
+                pareto_front.push(element.clone());
+            }
+        }
+        let result = ParetoFrontResult::new(pareto_front, initial_time, generation);
+        Ok(result)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration struct for the SPEA2 algorithm
+#[derive(Debug, Clone)]
+pub struct Spea2Config {
+    /// Generations
+    pub number_generations: usize,
+    /// Population size, also the number of offsprings per generation
+    pub population_size: usize,
+    /// Size of the archive kept between generations
+    pub archive_size: usize,
+    /// Mutation rate
+    pub mutation_rate: f64,
+    /// Neighbor used for the density estimation, the square root of the number of individuals if not provided
+    pub density_neighbor: Option<usize>,
+}
+
+impl Default for Spea2Config {
+    fn default() -> Self {
+        Self {
+            number_generations: 100,
+            population_size: 100,
+            archive_size: 100,
+            mutation_rate: 0.1,
+            density_neighbor: None,
+        }
+    }
+}
+
+impl Spea2Config {
+    pub fn new(
+        number_generations: usize,
+        population_size: usize,
+        archive_size: usize,
+        mutation_rate: f64,
+        density_neighbor: Option<usize>,
+    ) -> Result<Self, AlgorithmError> {
+        // Validate the data
+        if !(0.0..=1.0).contains(&mutation_rate) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the mutation rate should be between 0 and 1.",
+            ));
+        }
+        if population_size < 2 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the population should contain at least two individuals.",
+            ));
+        }
+        if archive_size == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the archive should be able to hold at least one solution.",
+            ));
+        }
+        if density_neighbor == Some(0) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the density neighbor should be at least the first one.",
+            ));
+        }
+        Ok(Spea2Config {
+            number_generations,
+            population_size,
+            archive_size,
+            mutation_rate,
+            density_neighbor,
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+    step_counting_hill_climbing::config::{StepCounting, StepCountingConfig},
+};
+
+/// Main algorithm implementation for the step counting hill climbing algorithm, accepting the
+/// solutions below a cost bound which is reset to the current objective every few steps
+pub struct StepCountingHillClimbing {
+    /// Configuration object for the algorithm
+    pub config: StepCountingConfig,
+}
+
+impl StepCountingHillClimbing {
+    /// Constructor to create a new StepCountingHillClimbing struct
+    pub fn new(config: StepCountingConfig) -> Self {
+        StepCountingHillClimbing { config }
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+
+        // Initialize useful mutable variables
+        let mut cost_bound = initial_solution.objective();
+        let mut current_solution = initial_solution;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+        let mut counted_steps = 0;
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            let new_solution = current_solution
+                .new_solution(rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+
+            // Accept the solutions below the cost bound or improving on the current one
+            let is_improving = new_solution.objective() < current_solution.objective();
+            let accepted = new_solution.objective() < cost_bound
+                || new_solution.objective() <= current_solution.objective();
+            if accepted {
+                current_solution = new_solution;
+                if current_solution.objective() < best_solution.objective() {
+                    best_solution = current_solution.clone();
+                }
+            }
+
+            // Count the step and reset the cost bound when the limit is reached
+            let counted = match self.config.counting {
+                StepCounting::AllMoves => true,
+                StepCounting::AcceptedMoves => accepted,
+                StepCounting::ImprovingMoves => is_improving,
+            };
+            if counted {
+                counted_steps += 1;
+            }
+            if counted_steps >= self.config.step_limit {
+                cost_bound = current_solution.objective();
+                counted_steps = 0;
+            }
+
+            // Update the iteration counter
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
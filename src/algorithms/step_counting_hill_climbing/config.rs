@@ -0,0 +1,57 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Moves counted towards the step limit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StepCounting {
+    /// Every generated move is counted
+    AllMoves,
+    /// Only accepted moves are counted
+    AcceptedMoves,
+    /// Only moves improving on the current solution are counted
+    ImprovingMoves,
+}
+
+/// Configuration for the step counting hill climbing algorithm
+#[derive(Debug, Clone)]
+pub struct StepCountingConfig {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Number of counted steps after which the cost bound is reset to the current objective
+    pub step_limit: usize,
+    /// Moves counted towards the step limit
+    pub counting: StepCounting,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl StepCountingConfig {
+    pub fn new(
+        max_iterations: usize,
+        step_limit: usize,
+        counting: StepCounting,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if step_limit == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the step limit should be at least 1.",
+            ));
+        }
+        Ok(StepCountingConfig {
+            max_iterations,
+            step_limit,
+            counting,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for StepCountingConfig {
+    fn default() -> Self {
+        StepCountingConfig {
+            max_iterations: 1_000,
+            step_limit: 50,
+            counting: StepCounting::AllMoves,
+            stop_threshold: None,
+        }
+    }
+}
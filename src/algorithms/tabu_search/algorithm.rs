@@ -0,0 +1,123 @@
+use std::{collections::HashMap, fmt::Debug, hash::Hash, time::Instant};
+
+use rand::{Rng, seq::index::sample};
+
+use crate::{
+    algorithms::{
+        errors::AlgorithmError,
+        simulated_annealing::algorithm::SimulationResult,
+        tabu_search::config::{AspirationCriterion, TabuSearchConfig},
+    },
+    problems::{ProblemError, ProblemSolution},
+};
+
+/// Trait for solutions explored through explicit moves
+pub trait TabuCompatible: Clone + Debug + ProblemSolution {
+    /// Move transforming a solution into one of its neighbors
+    type Move: Clone + Debug;
+    /// Attribute of a move stored in the tabu list
+    type MoveKey: Clone + Debug + Eq + Hash;
+
+    /// List all the moves available from this solution
+    fn candidate_moves(&self) -> Vec<Self::Move>;
+
+    /// Key identifying the move (or its attribute) in the tabu list
+    fn move_key(&self, candidate_move: &Self::Move) -> Self::MoveKey;
+
+    /// Generate the neighbor obtained by applying the move
+    fn apply_move(&self, candidate_move: &Self::Move) -> Result<Self, ProblemError>;
+}
+
+/// Main algorithm implementation for the tabu search algorithm
+pub struct TabuSearchAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: TabuSearchConfig,
+}
+
+impl TabuSearchAlgorithm {
+    /// Constructor to create a new TabuSearchAlgorithm struct
+    pub fn new(config: TabuSearchConfig) -> Self {
+        TabuSearchAlgorithm { config }
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: TabuCompatible,
+    {
+        let initial_time = Instant::now();
+
+        // Initialize useful mutable variables
+        let mut current_solution = initial_solution;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+        // Tabu list mapping each move key to the iteration at which it stops being tabu
+        let mut tabu_list: HashMap<T::MoveKey, usize> = HashMap::new();
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            let candidate_moves = current_solution.candidate_moves();
+            if candidate_moves.is_empty() {
+                break;
+            }
+
+            // Evaluate a sample of the neighborhood and keep the best admissible move
+            let sample_size = self
+                .config
+                .neighborhood_sample_size
+                .min(candidate_moves.len());
+            let mut best_candidate: Option<(T, T::MoveKey)> = None;
+            for index in sample(rng, candidate_moves.len(), sample_size) {
+                let candidate_move = &candidate_moves[index];
+                let neighbor = current_solution
+                    .apply_move(candidate_move)
+                    .map_err(|_| AlgorithmError::ExecutionError("could not apply move."))?;
+                let key = current_solution.move_key(candidate_move);
+
+                let is_tabu = tabu_list.get(&key).is_some_and(|&end| end > iteration);
+                let is_aspirated = self.config.aspiration_criterion
+                    == AspirationCriterion::BestSolution
+                    && neighbor.objective() < best_solution.objective();
+                if is_tabu && !is_aspirated {
+                    continue;
+                }
+
+                let is_better = best_candidate
+                    .as_ref()
+                    .is_none_or(|(solution, _)| neighbor.objective() < solution.objective());
+                if is_better {
+                    best_candidate = Some((neighbor, key));
+                }
+            }
+
+            // Move to the best admissible neighbor, even if it is worse than the current solution
+            if let Some((neighbor, key)) = best_candidate {
+                current_solution = neighbor;
+                tabu_list.insert(key, iteration + 1 + self.config.tabu_tenure);
+
+                if current_solution.objective() < best_solution.objective() {
+                    best_solution = current_solution.clone();
+                }
+            }
+
+            // Forget the moves which are no longer tabu and update the iteration counter
+            iteration += 1;
+            tabu_list.retain(|_, end| *end > iteration);
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (best_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Aspiration criterion used to lift the tabu status of a move
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AspirationCriterion {
+    /// Tabu moves are never accepted
+    Disabled,
+    /// Tabu moves are accepted if they improve on the best solution found so far
+    BestSolution,
+}
+
+/// Configuration for the tabu search algorithm
+#[derive(Debug, Clone)]
+pub struct TabuSearchConfig {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Number of iterations during which a move stays tabu
+    pub tabu_tenure: usize,
+    /// Number of candidate moves sampled at each iteration
+    pub neighborhood_sample_size: usize,
+    /// Criterion allowing tabu moves to be selected
+    pub aspiration_criterion: AspirationCriterion,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl TabuSearchConfig {
+    pub fn new(
+        max_iterations: usize,
+        tabu_tenure: usize,
+        neighborhood_sample_size: usize,
+        aspiration_criterion: AspirationCriterion,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if neighborhood_sample_size == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the neighborhood sample size should be at least 1.",
+            ));
+        }
+        Ok(TabuSearchConfig {
+            max_iterations,
+            tabu_tenure,
+            neighborhood_sample_size,
+            aspiration_criterion,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for TabuSearchConfig {
+    fn default() -> Self {
+        TabuSearchConfig {
+            max_iterations: 1_000,
+            tabu_tenure: 5,
+            neighborhood_sample_size: 20,
+            aspiration_criterion: AspirationCriterion::BestSolution,
+            stop_threshold: None,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::algorithms::{
+    errors::AlgorithmError,
+    simulated_annealing::algorithm::{SimulatedAnnealing, SimulationResult},
+    threshold_accepting::config::ThresholdAcceptingConfig,
+};
+
+/// Main algorithm implementation for the threshold accepting algorithm
+pub struct ThresholdAcceptingAlgorithm {
+    /// Configuration object for the algorithm
+    pub config: ThresholdAcceptingConfig,
+}
+
+impl ThresholdAcceptingAlgorithm {
+    /// Constructor to create a new ThresholdAcceptingAlgorithm struct
+    pub fn new(config: ThresholdAcceptingConfig) -> Self {
+        ThresholdAcceptingAlgorithm { config }
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: SimulatedAnnealing,
+    {
+        let initial_time = Instant::now();
+
+        // Initialize useful mutable variables
+        let mut current_solution = initial_solution;
+        let mut best_solution = current_solution.clone();
+        let mut iteration = 0;
+
+        // Go through the sequence of thresholds
+        'thresholds: for threshold in self.config.thresholds.iter() {
+            for _ in 0..self.config.iterations_per_threshold {
+                let new_solution = current_solution.new_solution(rng).map_err(|_| {
+                    AlgorithmError::ExecutionError("could not generate new solution.")
+                })?;
+
+                // Accept any move which does not worsen the objective by more than the threshold
+                let delta_objective = new_solution.objective() - current_solution.objective();
+                if delta_objective < *threshold || delta_objective <= 0.0 {
+                    current_solution = new_solution;
+                    if current_solution.objective() < best_solution.objective() {
+                        best_solution = current_solution.clone();
+                    }
+                }
+
+                // Update the iteration counter
+                iteration += 1;
+
+                // Early stopping check
+                if self.config.stop_threshold.is_some()
+                    && (best_solution.objective() < self.config.stop_threshold.unwrap())
+                {
+                    break 'thresholds;
+                }
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(best_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Configuration for the threshold accepting algorithm
+#[derive(Debug, Clone)]
+pub struct ThresholdAcceptingConfig {
+    /// Sequence of thresholds, a worsening move is accepted if it is below the current threshold
+    pub thresholds: Vec<f64>,
+    /// Number of iterations spent at each threshold
+    pub iterations_per_threshold: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl ThresholdAcceptingConfig {
+    pub fn new(
+        thresholds: Vec<f64>,
+        iterations_per_threshold: usize,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if thresholds.is_empty() || thresholds.iter().any(|threshold| *threshold < 0.0) {
+            return Err(AlgorithmError::ConfigurationError(
+                "the thresholds should be a non-empty sequence of non-negative values.",
+            ));
+        }
+        if iterations_per_threshold == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "each threshold should be used for at least one iteration.",
+            ));
+        }
+        Ok(ThresholdAcceptingConfig {
+            thresholds,
+            iterations_per_threshold,
+            stop_threshold,
+        })
+    }
+
+    /// Geometric sequence of thresholds starting from the initial threshold
+    pub fn geometric_thresholds(
+        initial_threshold: f64,
+        ratio: f64,
+        number_thresholds: usize,
+    ) -> Vec<f64> {
+        (0..number_thresholds)
+            .map(|index| initial_threshold * ratio.powi(index as i32))
+            .collect()
+    }
+}
+
+impl Default for ThresholdAcceptingConfig {
+    fn default() -> Self {
+        ThresholdAcceptingConfig {
+            thresholds: Self::geometric_thresholds(10.0, 0.9, 50),
+            iterations_per_threshold: 20,
+            stop_threshold: None,
+        }
+    }
+}
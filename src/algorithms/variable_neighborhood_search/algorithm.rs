@@ -0,0 +1,176 @@
+use std::{fmt::Debug, time::Instant};
+
+use rand::Rng;
+
+use crate::{
+    algorithms::{
+        errors::AlgorithmError,
+        simulated_annealing::algorithm::SimulationResult,
+        variable_neighborhood_search::config::{
+            VariableNeighborhoodSearchConfig, VariableNeighborhoodVariant,
+        },
+    },
+    problems::{ProblemError, ProblemSolution},
+};
+
+/// Trait for solutions with an ordered list of neighborhood structures
+pub trait VariableNeighborhoodCompatible: Clone + Debug + ProblemSolution {
+    /// Number of neighborhood structures, usually ordered from the smallest to the largest
+    fn number_neighborhoods(&self) -> usize;
+
+    /// Draw a random neighbor in the given neighborhood, used to shake the solution. The move
+    /// size only matters to the neighborhoods of variable size.
+    fn random_neighbor(
+        &self,
+        neighborhood: usize,
+        move_size: usize,
+        rng: &mut impl Rng,
+    ) -> Result<Self, ProblemError>;
+
+    /// Enumerate the neighbors in the given neighborhood, used by the descents. The enumeration
+    /// is lazy since the descents stop at the first improving neighbor.
+    fn neighbors(
+        &self,
+        neighborhood: usize,
+        move_size: usize,
+    ) -> Result<Box<dyn Iterator<Item = Self> + '_>, ProblemError>;
+}
+
+/// Main algorithm implementation for the variable neighborhood search
+pub struct VariableNeighborhoodSearch {
+    /// Configuration object for the algorithm
+    pub config: VariableNeighborhoodSearchConfig,
+}
+
+impl VariableNeighborhoodSearch {
+    /// Constructor to create a new VariableNeighborhoodSearch struct
+    pub fn new(config: VariableNeighborhoodSearchConfig) -> Self {
+        VariableNeighborhoodSearch { config }
+    }
+
+    /// First neighbor of the solution in the given neighborhood improving on the solution
+    fn first_improvement<T>(
+        &self,
+        solution: &T,
+        neighborhood: usize,
+    ) -> Result<Option<T>, AlgorithmError>
+    where
+        T: VariableNeighborhoodCompatible,
+    {
+        let mut neighbors = solution
+            .neighbors(neighborhood, self.config.move_size)
+            .map_err(|_| AlgorithmError::ExecutionError("could not list the neighbors."))?;
+        Ok(neighbors.find(|neighbor| neighbor.objective() < solution.objective()))
+    }
+
+    /// Descend in the first neighborhood until reaching a local optimum
+    fn local_descent<T>(&self, solution: T) -> Result<T, AlgorithmError>
+    where
+        T: VariableNeighborhoodCompatible,
+    {
+        let mut current_solution = solution;
+        while let Some(neighbor) = self.first_improvement(&current_solution, 0)? {
+            current_solution = neighbor;
+        }
+        Ok(current_solution)
+    }
+
+    /// Variable neighborhood descent: go back to the first neighborhood after each improvement,
+    /// and move to the next one otherwise. Returns the local optimum and the number of
+    /// neighborhood explorations.
+    fn variable_neighborhood_descent<T>(
+        &self,
+        solution: T,
+        max_explorations: usize,
+    ) -> Result<(T, usize), AlgorithmError>
+    where
+        T: VariableNeighborhoodCompatible,
+    {
+        let mut current_solution = solution;
+        let mut neighborhood = 0;
+        let mut explorations = 0;
+        while neighborhood < current_solution.number_neighborhoods()
+            && explorations < max_explorations
+        {
+            explorations += 1;
+            match self.first_improvement(&current_solution, neighborhood)? {
+                Some(neighbor) => {
+                    current_solution = neighbor;
+                    neighborhood = 0;
+                }
+                None => neighborhood += 1,
+            }
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (current_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+        Ok((current_solution, explorations))
+    }
+
+    /// Find a solution with minimal objective function
+    pub fn execute<T>(
+        &self,
+        initial_solution: T,
+        rng: &mut impl Rng,
+    ) -> Result<SimulationResult<T>, AlgorithmError>
+    where
+        T: VariableNeighborhoodCompatible,
+    {
+        let initial_time = Instant::now();
+        if initial_solution.number_neighborhoods() == 0 {
+            return Err(AlgorithmError::ExecutionError(
+                "the solution should provide at least one neighborhood.",
+            ));
+        }
+
+        // The descent is deterministic and runs on its own
+        if self.config.variant == VariableNeighborhoodVariant::Descent {
+            let (solution, explorations) =
+                self.variable_neighborhood_descent(initial_solution, self.config.max_iterations)?;
+            return Ok(SimulationResult::new(solution, initial_time, explorations));
+        }
+
+        // Initialize useful mutable variables
+        let mut current_solution = initial_solution;
+        let mut neighborhood = 0;
+        let mut iteration = 0;
+
+        // Loop until the final criterion is reached
+        while iteration < self.config.max_iterations {
+            // Shake the solution in the current neighborhood, then descend to a local optimum
+            let shaken = current_solution
+                .random_neighbor(neighborhood, self.config.move_size, rng)
+                .map_err(|_| AlgorithmError::ExecutionError("could not generate new solution."))?;
+            let local_optimum = match self.config.variant {
+                VariableNeighborhoodVariant::Basic => self.local_descent(shaken)?,
+                _ => self.variable_neighborhood_descent(shaken, usize::MAX)?.0,
+            };
+
+            // Move or go to the next neighborhood
+            if local_optimum.objective() < current_solution.objective() {
+                current_solution = local_optimum;
+                neighborhood = 0;
+            } else {
+                neighborhood = (neighborhood + 1) % current_solution.number_neighborhoods();
+            }
+
+            // Update the iteration counter
+            iteration += 1;
+
+            // Early stopping check
+            if self.config.stop_threshold.is_some()
+                && (current_solution.objective() < self.config.stop_threshold.unwrap())
+            {
+                break;
+            }
+        }
+
+        // Return the solution
+        let result = SimulationResult::new(current_solution, initial_time, iteration);
+        Ok(result)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::algorithms::errors::AlgorithmError;
+
+/// Variant of the variable neighborhood search
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VariableNeighborhoodVariant {
+    /// Shake in the current neighborhood, then descend in the first neighborhood
+    Basic,
+    /// Deterministic first-improvement descent changing neighborhood when stuck
+    Descent,
+    /// Shake in the current neighborhood, then apply a variable neighborhood descent
+    General,
+}
+
+/// Configuration for the variable neighborhood search algorithm
+#[derive(Debug, Clone)]
+pub struct VariableNeighborhoodSearchConfig {
+    /// Maximum number of iterations, either shakes or neighborhood explorations for the descent
+    pub max_iterations: usize,
+    /// Variant of the search
+    pub variant: VariableNeighborhoodVariant,
+    /// Number of elements changed together by the neighborhoods of variable size, such as the
+    /// multi-flip neighborhood of the knapsack
+    pub move_size: usize,
+    /// Threshold under which the objective function should stop (if the target value is zero)
+    pub stop_threshold: Option<f64>,
+}
+
+impl VariableNeighborhoodSearchConfig {
+    pub fn new(
+        max_iterations: usize,
+        variant: VariableNeighborhoodVariant,
+        move_size: usize,
+        stop_threshold: Option<f64>,
+    ) -> Result<Self, AlgorithmError> {
+        if max_iterations == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the search should run for at least one iteration.",
+            ));
+        }
+        if move_size == 0 {
+            return Err(AlgorithmError::ConfigurationError(
+                "the moves should change at least one element.",
+            ));
+        }
+        Ok(VariableNeighborhoodSearchConfig {
+            max_iterations,
+            variant,
+            move_size,
+            stop_threshold,
+        })
+    }
+}
+
+impl Default for VariableNeighborhoodSearchConfig {
+    fn default() -> Self {
+        VariableNeighborhoodSearchConfig {
+            max_iterations: 100,
+            variant: VariableNeighborhoodVariant::General,
+            move_size: 3,
+            stop_threshold: None,
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod algorithms;
+pub mod metrics;
 pub mod problems;
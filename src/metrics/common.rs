@@ -0,0 +1,43 @@
+use crate::metrics::errors::MetricError;
+
+/// Check that the set is not empty and that all vectors have the same number of objectives
+pub(crate) fn number_objectives(points: &[Vec<f64>]) -> Result<usize, MetricError> {
+    let first = points.first().ok_or(MetricError::InvalidInputError(
+        "the set of objective vectors should not be empty.",
+    ))?;
+    if first.is_empty() {
+        return Err(MetricError::InvalidInputError(
+            "the objective vectors should have at least one objective.",
+        ));
+    }
+    if points.iter().any(|point| point.len() != first.len()) {
+        return Err(MetricError::InvalidInputError(
+            "all objective vectors should have the same number of objectives.",
+        ));
+    }
+    Ok(first.len())
+}
+
+/// Check that both sets are valid and share the same number of objectives
+pub(crate) fn check_sets(
+    front: &[Vec<f64>],
+    reference_front: &[Vec<f64>],
+) -> Result<usize, MetricError> {
+    let dimension = number_objectives(front)?;
+    if number_objectives(reference_front)? != dimension {
+        return Err(MetricError::InvalidInputError(
+            "the front and the reference front should have the same number of objectives.",
+        ));
+    }
+    Ok(dimension)
+}
+
+/// Euclidean distance between two objective vectors
+pub(crate) fn euclidean_distance(first: &[f64], second: &[f64]) -> f64 {
+    first
+        .iter()
+        .zip(second.iter())
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>()
+        .sqrt()
+}
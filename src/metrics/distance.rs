@@ -0,0 +1,92 @@
+use crate::metrics::{
+    common::{check_sets, euclidean_distance},
+    errors::MetricError,
+};
+
+/// Average over the first set of the distance to the closest point of the second set
+fn average_closest_distance<F>(from: &[Vec<f64>], to: &[Vec<f64>], distance: F) -> f64
+where
+    F: Fn(&[f64], &[f64]) -> f64,
+{
+    let total: f64 = from
+        .iter()
+        .map(|point| {
+            to.iter()
+                .map(|other| distance(point, other))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .sum();
+    total / from.len() as f64
+}
+
+/// Generational distance: average distance from the front to the closest reference point
+pub fn generational_distance(
+    front: &[Vec<f64>],
+    reference_front: &[Vec<f64>],
+) -> Result<f64, MetricError> {
+    check_sets(front, reference_front)?;
+    Ok(average_closest_distance(
+        front,
+        reference_front,
+        euclidean_distance,
+    ))
+}
+
+/// Inverted generational distance: average distance from the reference front to the closest point of the front
+pub fn inverted_generational_distance(
+    front: &[Vec<f64>],
+    reference_front: &[Vec<f64>],
+) -> Result<f64, MetricError> {
+    check_sets(front, reference_front)?;
+    Ok(average_closest_distance(
+        reference_front,
+        front,
+        euclidean_distance,
+    ))
+}
+
+/// Inverted generational distance plus, only counting how much the front is worse than the
+/// reference points so that the indicator is weakly Pareto-compliant (minimization)
+pub fn inverted_generational_distance_plus(
+    front: &[Vec<f64>],
+    reference_front: &[Vec<f64>],
+) -> Result<f64, MetricError> {
+    check_sets(front, reference_front)?;
+    Ok(average_closest_distance(
+        reference_front,
+        front,
+        |reference, point| {
+            reference
+                .iter()
+                .zip(point.iter())
+                .map(|(z, a)| (a - z).max(0.0).powi(2))
+                .sum::<f64>()
+                .sqrt()
+        },
+    ))
+}
+
+/// Additive epsilon indicator: smallest translation of the front so that it weakly dominates
+/// every reference point (minimization)
+pub fn epsilon_indicator(
+    front: &[Vec<f64>],
+    reference_front: &[Vec<f64>],
+) -> Result<f64, MetricError> {
+    check_sets(front, reference_front)?;
+    let epsilon = reference_front
+        .iter()
+        .map(|reference| {
+            front
+                .iter()
+                .map(|point| {
+                    point
+                        .iter()
+                        .zip(reference.iter())
+                        .map(|(a, z)| a - z)
+                        .fold(f64::NEG_INFINITY, f64::max)
+                })
+                .fold(f64::INFINITY, f64::min)
+        })
+        .fold(f64::NEG_INFINITY, f64::max);
+    Ok(epsilon)
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error)]
+pub enum MetricError {
+    #[error("Error with the objective vectors given to the indicator: {0}")]
+    InvalidInputError(&'static str),
+}
@@ -0,0 +1,175 @@
+use rand::Rng;
+
+use crate::{
+    algorithms::dominates,
+    metrics::{common::number_objectives, errors::MetricError},
+};
+
+/// Largest number of objectives for which the hypervolume is computed exactly
+pub const MAX_EXACT_HYPERVOLUME_OBJECTIVES: usize = 6;
+
+/// Number of samples of the Monte Carlo estimate used beyond the exact computation
+pub const HYPERVOLUME_MONTE_CARLO_SAMPLES: usize = 100_000;
+
+/// Keep the points strictly better than the reference point and not dominated by another point
+fn relevant_points(points: &[Vec<f64>], reference_point: &[f64]) -> Vec<Vec<f64>> {
+    let inside: Vec<&Vec<f64>> = points
+        .iter()
+        .filter(|point| point.iter().zip(reference_point.iter()).all(|(p, r)| p < r))
+        .collect();
+    let mut kept: Vec<Vec<f64>> = Vec::with_capacity(inside.len());
+    for point in inside.iter() {
+        let is_dominated = inside.iter().any(|other| dominates(other, point));
+        if !is_dominated && !kept.contains(point) {
+            kept.push((*point).clone());
+        }
+    }
+    kept
+}
+
+/// Check the reference point and the number of objectives of the points
+fn check_reference_point(
+    points: &[Vec<f64>],
+    reference_point: &[f64],
+) -> Result<usize, MetricError> {
+    let dimension = number_objectives(points)?;
+    if reference_point.len() != dimension {
+        return Err(MetricError::InvalidInputError(
+            "the reference point should have the same number of objectives as the points.",
+        ));
+    }
+    Ok(dimension)
+}
+
+/// Volume dominated by a single point
+fn inclusive_hypervolume(point: &[f64], reference_point: &[f64]) -> f64 {
+    point
+        .iter()
+        .zip(reference_point.iter())
+        .map(|(p, r)| r - p)
+        .product()
+}
+
+/// Sweep of the sorted points in two dimensions
+fn hypervolume_2d(mut points: Vec<Vec<f64>>, reference_point: &[f64]) -> f64 {
+    points.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap_or(std::cmp::Ordering::Equal));
+    let mut volume = 0.0;
+    let mut previous_height = reference_point[1];
+    for point in points.iter() {
+        volume += (reference_point[0] - point[0]) * (previous_height - point[1]);
+        previous_height = point[1];
+    }
+    volume
+}
+
+/// WFG algorithm: sum of the exclusive hypervolumes of the points sorted on the last objective
+fn wfg(mut points: Vec<Vec<f64>>, reference_point: &[f64]) -> f64 {
+    if points.len() == 1 {
+        return inclusive_hypervolume(&points[0], reference_point);
+    }
+    if reference_point.len() == 2 {
+        return hypervolume_2d(points, reference_point);
+    }
+    let last = reference_point.len() - 1;
+    points.sort_by(|a, b| {
+        b[last]
+            .partial_cmp(&a[last])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let mut volume = 0.0;
+    for (index, point) in points.iter().enumerate() {
+        // The exclusive part is the point volume minus the volume shared with the next points
+        let limited: Vec<Vec<f64>> = points[index + 1..]
+            .iter()
+            .map(|other| {
+                point
+                    .iter()
+                    .zip(other.iter())
+                    .map(|(p, o)| p.max(*o))
+                    .collect()
+            })
+            .collect();
+        let limited = relevant_points(&limited, reference_point);
+        volume += inclusive_hypervolume(point, reference_point);
+        if !limited.is_empty() {
+            volume -= wfg(limited, reference_point);
+        }
+    }
+    volume
+}
+
+/// Exact hypervolume dominated by the points and bounded by the reference point (minimization),
+/// computed with the WFG algorithm
+pub fn exact_hypervolume(points: &[Vec<f64>], reference_point: &[f64]) -> Result<f64, MetricError> {
+    check_reference_point(points, reference_point)?;
+    let points = relevant_points(points, reference_point);
+    if points.is_empty() {
+        return Ok(0.0);
+    }
+    Ok(wfg(points, reference_point))
+}
+
+/// Monte Carlo estimate of the hypervolume, sampling uniformly the box between the ideal point
+/// of the set and the reference point
+pub fn monte_carlo_hypervolume(
+    points: &[Vec<f64>],
+    reference_point: &[f64],
+    number_samples: usize,
+    rng: &mut impl Rng,
+) -> Result<f64, MetricError> {
+    let dimension = check_reference_point(points, reference_point)?;
+    if number_samples == 0 {
+        return Err(MetricError::InvalidInputError(
+            "the estimate needs at least one sample.",
+        ));
+    }
+    let points = relevant_points(points, reference_point);
+    if points.is_empty() {
+        return Ok(0.0);
+    }
+    let lower_bounds: Vec<f64> = (0..dimension)
+        .map(|objective| {
+            points
+                .iter()
+                .map(|point| point[objective])
+                .fold(f64::INFINITY, f64::min)
+        })
+        .collect();
+    let box_volume = inclusive_hypervolume(&lower_bounds, reference_point);
+    let mut sample = vec![0.0; dimension];
+    let mut number_dominated: usize = 0;
+    for _ in 0..number_samples {
+        for ((value, low), high) in sample
+            .iter_mut()
+            .zip(lower_bounds.iter())
+            .zip(reference_point.iter())
+        {
+            *value = rng.random_range(*low..*high);
+        }
+        if points
+            .iter()
+            .any(|point| point.iter().zip(sample.iter()).all(|(p, s)| p <= s))
+        {
+            number_dominated += 1;
+        }
+    }
+    Ok(box_volume * number_dominated as f64 / number_samples as f64)
+}
+
+/// Hypervolume of the points, exact up to `MAX_EXACT_HYPERVOLUME_OBJECTIVES` objectives and
+/// estimated with `HYPERVOLUME_MONTE_CARLO_SAMPLES` samples beyond
+pub fn hypervolume(
+    points: &[Vec<f64>],
+    reference_point: &[f64],
+    rng: &mut impl Rng,
+) -> Result<f64, MetricError> {
+    if reference_point.len() <= MAX_EXACT_HYPERVOLUME_OBJECTIVES {
+        return exact_hypervolume(points, reference_point);
+    }
+    monte_carlo_hypervolume(
+        points,
+        reference_point,
+        HYPERVOLUME_MONTE_CARLO_SAMPLES,
+        rng,
+    )
+}
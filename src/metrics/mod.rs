@@ -0,0 +1,16 @@
+mod common;
+mod distance;
+mod errors;
+mod hypervolume;
+mod spread;
+
+pub use crate::metrics::distance::{
+    epsilon_indicator, generational_distance, inverted_generational_distance,
+    inverted_generational_distance_plus,
+};
+pub use crate::metrics::errors::MetricError;
+pub use crate::metrics::hypervolume::{
+    HYPERVOLUME_MONTE_CARLO_SAMPLES, MAX_EXACT_HYPERVOLUME_OBJECTIVES, exact_hypervolume,
+    hypervolume, monte_carlo_hypervolume,
+};
+pub use crate::metrics::spread::spread;
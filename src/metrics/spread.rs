@@ -0,0 +1,55 @@
+use crate::metrics::{
+    common::{check_sets, euclidean_distance},
+    errors::MetricError,
+};
+
+/// Generalized spread Δ of a front, measuring both the extent and the uniformity of the points.
+/// It is 0 for a perfectly uniform front reaching the extreme points of the reference front.
+pub fn spread(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> Result<f64, MetricError> {
+    let dimension = check_sets(front, reference_front)?;
+
+    // Distance between each extreme point of the reference front and the front
+    let extreme_distances: f64 = (0..dimension)
+        .map(|objective| {
+            let extreme = reference_front
+                .iter()
+                .min_by(|a, b| {
+                    a[objective]
+                        .partial_cmp(&b[objective])
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                // Cannot fail, `check_sets` rejects an empty reference front
+                .expect("the reference front is not empty");
+            front
+                .iter()
+                .map(|point| euclidean_distance(extreme, point))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .sum();
+
+    // Distance of each point of the front to its nearest neighbor, zero for a single point
+    let nearest_distances: Vec<f64> = front
+        .iter()
+        .enumerate()
+        .map(|(index, point)| {
+            front
+                .iter()
+                .enumerate()
+                .filter(|(other_index, _)| *other_index != index)
+                .map(|(_, other)| euclidean_distance(point, other))
+                .reduce(f64::min)
+                .unwrap_or(0.0)
+        })
+        .collect();
+    let mean_distance = nearest_distances.iter().sum::<f64>() / front.len() as f64;
+    let deviation: f64 = nearest_distances
+        .iter()
+        .map(|distance| (distance - mean_distance).abs())
+        .sum();
+
+    let denominator = extreme_distances + front.len() as f64 * mean_distance;
+    if denominator == 0.0 {
+        return Ok(0.0);
+    }
+    Ok((extreme_distances + deviation) / denominator)
+}
@@ -1,5 +1,36 @@
+use std::fmt::Debug;
+
+use crate::problems::errors::ProblemError;
+
 /// Trait describing a generic solution
 pub trait ProblemSolution {
     /// All solutions are expected to have an objective value which must be minimized.
     fn objective(&self) -> f64;
 }
+
+/// Trait describing a solution encoded as a bounded vector of real numbers
+pub trait RealVectorSolution: Clone + Debug + ProblemSolution {
+    /// Coordinates of the solution
+    fn position(&self) -> &[f64];
+
+    /// Lower and upper bounds of each coordinate
+    fn bounds(&self) -> &[(f64, f64)];
+
+    /// Create a solution of the same problem at the given position
+    fn with_position(&self, position: Vec<f64>) -> Result<Self, ProblemError>;
+}
+
+/// Trait describing a solution encoded as a fixed-length vector of bits
+pub trait BinaryVectorSolution: Clone + Debug + ProblemSolution {
+    /// Bits of the solution, always of the same length for a given problem
+    fn bits(&self) -> Vec<bool>;
+
+    /// Create a solution of the same problem from the given bits
+    fn with_bits(&self, bits: &[bool]) -> Result<Self, ProblemError>;
+}
+
+/// Trait describing a solution with several objectives, all of which must be minimized
+pub trait MultiObjectiveSolution {
+    /// Values of the objectives, always in the same order and of the same length
+    fn objectives(&self) -> Vec<f64>;
+}
@@ -0,0 +1,152 @@
+use std::f64::consts::{E, PI};
+
+use rand::Rng;
+
+use crate::problems::errors::ProblemError;
+use crate::problems::{ProblemSolution, RealVectorSolution};
+
+/// Standard benchmark functions for continuous optimization, all with a minimal value of zero
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BenchmarkFunction {
+    /// Sum of squares, minimum at the origin
+    Sphere,
+    /// Highly multimodal function, minimum at the origin
+    Rastrigin,
+    /// Narrow curved valley, minimum at (1, ..., 1)
+    Rosenbrock,
+    /// Nearly flat outer region with a deep hole, minimum at the origin
+    Ackley,
+}
+
+impl BenchmarkFunction {
+    /// Evaluate the function at the given position
+    pub fn evaluate(&self, position: &[f64]) -> f64 {
+        let dimension = position.len() as f64;
+        match self {
+            BenchmarkFunction::Sphere => position.iter().map(|x| x * x).sum(),
+            BenchmarkFunction::Rastrigin => {
+                10.0 * dimension
+                    + position
+                        .iter()
+                        .map(|x| x * x - 10.0 * (2.0 * PI * x).cos())
+                        .sum::<f64>()
+            }
+            BenchmarkFunction::Rosenbrock => position
+                .windows(2)
+                .map(|w| 100.0 * (w[1] - w[0] * w[0]).powi(2) + (1.0 - w[0]).powi(2))
+                .sum(),
+            BenchmarkFunction::Ackley => {
+                if position.is_empty() {
+                    return 0.0;
+                }
+                let squares = position.iter().map(|x| x * x).sum::<f64>() / dimension;
+                let cosines =
+                    position.iter().map(|x| (2.0 * PI * x).cos()).sum::<f64>() / dimension;
+                -20.0 * (-0.2 * squares.sqrt()).exp() - cosines.exp() + 20.0 + E
+            }
+        }
+    }
+}
+
+/// Continuous problem minimizing a benchmark function over a box
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContinuousProblem {
+    /// Function to minimize
+    pub function: BenchmarkFunction,
+    /// Dimension of the search space
+    pub dimension: usize,
+    /// Lower and upper bounds of each coordinate
+    pub bounds: Vec<(f64, f64)>,
+    /// Optimal value if known
+    pub optimal_value: Option<f64>,
+}
+
+impl ContinuousProblem {
+    /// Constructor
+    pub fn new(function: BenchmarkFunction, bounds: &[(f64, f64)]) -> Result<Self, ProblemError> {
+        if bounds.is_empty() {
+            return Err(ProblemError::InitializationError(
+                "the problem should have at least one dimension.",
+            ));
+        }
+        if bounds.iter().any(|(lower, upper)| lower > upper) {
+            return Err(ProblemError::InitializationError(
+                "the lower bounds should be below the upper bounds.",
+            ));
+        }
+        Ok(ContinuousProblem {
+            function,
+            dimension: bounds.len(),
+            bounds: Vec::from(bounds),
+            optimal_value: Some(0.0),
+        })
+    }
+}
+
+/// Continuous problem solution
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContinuousSolution<'a> {
+    /// Coordinates of the solution
+    pub position: Vec<f64>,
+    /// Value of the function at the position
+    pub value: f64,
+    /// Reference to the problem
+    problem: &'a ContinuousProblem,
+}
+
+impl<'a> ContinuousSolution<'a> {
+    pub fn new(position: Vec<f64>, problem: &'a ContinuousProblem) -> Result<Self, ProblemError> {
+        if position.len() != problem.dimension {
+            return Err(ProblemError::NewSolutionError(
+                "the position should have the dimension of the problem.",
+            ));
+        }
+        let value = problem.function.evaluate(&position);
+        Ok(ContinuousSolution {
+            position,
+            value,
+            problem,
+        })
+    }
+
+    pub fn new_random(
+        problem: &'a ContinuousProblem,
+        rng: &mut impl Rng,
+    ) -> Result<Self, ProblemError> {
+        let position = problem
+            .bounds
+            .iter()
+            .map(|&(lower, upper)| rng.random_range(lower..=upper))
+            .collect();
+        Self::new(position, problem)
+    }
+}
+
+/// Partial ordering implementation for this solution
+impl<'a> PartialOrd for ContinuousSolution<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.objective().partial_cmp(&other.objective())
+    }
+}
+
+/// Objective of the continuous solution
+impl<'a> ProblemSolution for ContinuousSolution<'a> {
+    fn objective(&self) -> f64 {
+        self.value
+    }
+}
+
+/// Real-vector representation of the continuous solution
+impl<'a> RealVectorSolution for ContinuousSolution<'a> {
+    fn position(&self) -> &[f64] {
+        &self.position
+    }
+
+    fn bounds(&self) -> &[(f64, f64)] {
+        &self.problem.bounds
+    }
+
+    fn with_position(&self, position: Vec<f64>) -> Result<Self, ProblemError> {
+        Self::new(position, self.problem)
+    }
+}
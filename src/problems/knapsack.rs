@@ -2,13 +2,16 @@ use std::collections::HashSet;
 use std::fs;
 use std::path::PathBuf;
 
-use rand::Rng;
 use rand::seq::SliceRandom;
 use rand::seq::index::sample;
+use rand::{Rng, RngCore};
 
-use crate::algorithms::{GeneticCompatible, SimulatedAnnealing};
-use crate::problems::ProblemSolution;
+use crate::algorithms::{
+    ConstructionGraph, DestroyOperator, GeneticCompatible, RepairOperator, SimulatedAnnealing,
+    TabuCompatible, VariableNeighborhoodCompatible,
+};
 use crate::problems::errors::ProblemError;
+use crate::problems::{BinaryVectorSolution, MultiObjectiveSolution, ProblemSolution};
 
 /// Generic Knapsack problem struct
 #[derive(Debug, Clone, PartialEq)]
@@ -21,6 +24,9 @@ pub struct KnapsackProblem {
     pub all_values: Vec<f64>,
     /// All weights of items in the knapsack
     pub all_weights: Vec<f64>,
+    /// Second weight dimension of the items, minimized by the bi-objective knapsack. Defaults to
+    /// one per item, in which case the number of items is minimized.
+    secondary_weights: Vec<f64>,
     /// Optimal value if known
     pub optimal_value: Option<f64>,
 }
@@ -45,10 +51,29 @@ impl KnapsackProblem {
             max_weight,
             all_values: Vec::from(all_values),
             all_weights: Vec::from(all_weights),
+            secondary_weights: vec![1.0; number_items],
             optimal_value,
         })
     }
 
+    /// Set the second weight dimension of the items, used as the second objective
+    pub fn with_secondary_weights(self, secondary_weights: &[f64]) -> Result<Self, ProblemError> {
+        if secondary_weights.len() != self.number_items {
+            return Err(ProblemError::InitializationError(
+                "the secondary weights and values should have the same length.",
+            ));
+        }
+        Ok(KnapsackProblem {
+            secondary_weights: Vec::from(secondary_weights),
+            ..self
+        })
+    }
+
+    /// Second weight dimension of the items, as long as the values
+    pub fn secondary_weights(&self) -> &[f64] {
+        &self.secondary_weights
+    }
+
     /// Easily load it from file
     pub fn load_from_file(file_path: &PathBuf) -> Result<Vec<Self>, ProblemError> {
         let contents = fs::read_to_string(file_path)
@@ -104,6 +129,11 @@ impl KnapsackProblem {
             .collect::<Result<Vec<Self>, ProblemError>>()?;
         Ok(problems)
     }
+
+    /// Value per unit of weight of an item
+    fn value_ratio(&self, index: usize) -> f64 {
+        self.all_values[index] / self.all_weights[index].max(f64::EPSILON)
+    }
 }
 
 /// Knapsack problem solution
@@ -156,6 +186,57 @@ impl<'a> KnapsackSolution<'a> {
         }
         Self::new(current_items, problem)
     }
+
+    /// Create the neighbor solution where the given item is added or removed
+    pub fn flip_item(&self, index: usize) -> Self {
+        let mut solution = self.clone();
+        solution.toggle_item(index);
+        solution
+    }
+
+    /// Add or remove the given item in place
+    fn toggle_item(&mut self, index: usize) {
+        // If the item is already in the set, remove it, otherwise add it
+        if self.items.remove(&index) {
+            self.value -= self.problem.all_values[index];
+            self.weight -= self.problem.all_weights[index];
+        } else {
+            self.items.insert(index);
+            self.value += self.problem.all_values[index];
+            self.weight += self.problem.all_weights[index];
+        }
+    }
+
+    /// Create the neighbor solution where all the given items are added or removed
+    pub fn flip_items(&self, indices: &[usize]) -> Self {
+        let mut solution = self.clone();
+        for &index in indices {
+            solution.toggle_item(index);
+        }
+        solution
+    }
+
+    /// Add the items which still fit by decreasing value ratio, skipping the excluded item
+    fn greedy_completion(&self, excluded: Option<usize>) -> Self {
+        let mut candidates: Vec<usize> = (0..self.problem.number_items)
+            .filter(|index| !self.items.contains(index) && Some(*index) != excluded)
+            .collect();
+        candidates.sort_by(|&a, &b| {
+            self.problem
+                .value_ratio(b)
+                .partial_cmp(&self.problem.value_ratio(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        candidates
+            .into_iter()
+            .fold(self.clone(), |solution, index| {
+                if solution.weight + self.problem.all_weights[index] <= self.problem.max_weight {
+                    solution.flip_item(index)
+                } else {
+                    solution
+                }
+            })
+    }
 }
 
 /// Partial ordering implementation for this solution
@@ -165,7 +246,7 @@ impl<'a> PartialOrd for KnapsackSolution<'a> {
     }
 }
 
-///
+/// Objective of the knapsack solution, penalizing overweight solutions
 impl<'a> ProblemSolution for KnapsackSolution<'a> {
     fn objective(&self) -> f64 {
         if self.weight > self.problem.max_weight {
@@ -175,35 +256,74 @@ impl<'a> ProblemSolution for KnapsackSolution<'a> {
     }
 }
 
+/// Bi-objective knapsack: maximize the value while minimizing the secondary weight, which is the
+/// number of items unless secondary weights were given to the problem
+impl<'a> MultiObjectiveSolution for KnapsackSolution<'a> {
+    fn objectives(&self) -> Vec<f64> {
+        let secondary_weight = self
+            .items
+            .iter()
+            .map(|&index| self.problem.secondary_weights[index])
+            .sum();
+        vec![self.objective(), secondary_weight]
+    }
+}
+
+/// Bit-string encoding of the knapsack solution, where bit `i` tells if item `i` is selected
+impl<'a> BinaryVectorSolution for KnapsackSolution<'a> {
+    fn bits(&self) -> Vec<bool> {
+        (0..self.problem.number_items)
+            .map(|index| self.items.contains(&index))
+            .collect()
+    }
+
+    fn with_bits(&self, bits: &[bool]) -> Result<Self, ProblemError> {
+        if bits.len() != self.problem.number_items {
+            return Err(ProblemError::NewSolutionError(
+                "the number of bits should match the number of items.",
+            ));
+        }
+        let items = bits
+            .iter()
+            .enumerate()
+            .filter(|(_, selected)| **selected)
+            .map(|(index, _)| index);
+        Self::new(items, self.problem)
+    }
+}
+
 /// Implement the Simulated annealing methods for the knapsack problem
 impl<'a> SimulatedAnnealing for KnapsackSolution<'a> {
     fn new_solution(&self, rng: &mut impl Rng) -> Result<Self, ProblemError> {
-        let mut new_items = self.items.clone();
         let random_index = rng.random_range(0..self.problem.number_items);
-        let (new_value, new_weight) = match new_items.contains(&random_index) {
-            // If the item is already in the set, remove it
-            true => {
-                new_items.remove(&random_index);
-                (
-                    self.value - self.problem.all_values[random_index],
-                    self.weight - self.problem.all_weights[random_index],
-                )
-            }
-            // Otherwise add it
-            false => {
-                new_items.insert(random_index);
-                (
-                    self.value + self.problem.all_values[random_index],
-                    self.weight + self.problem.all_weights[random_index],
-                )
-            }
-        };
-        Ok(KnapsackSolution {
-            items: new_items,
-            value: new_value,
-            weight: new_weight,
-            problem: self.problem,
-        })
+        Ok(self.flip_item(random_index))
+    }
+
+    fn random_solution(&self, rng: &mut impl Rng) -> Result<Self, ProblemError> {
+        Self::new_random(None, self.problem, rng)
+    }
+}
+
+/// Implement the tabu search methods for the knapsack problem, where moves are item flips
+impl<'a> TabuCompatible for KnapsackSolution<'a> {
+    type Move = usize;
+    type MoveKey = usize;
+
+    fn candidate_moves(&self) -> Vec<usize> {
+        (0..self.problem.number_items).collect()
+    }
+
+    fn move_key(&self, candidate_move: &usize) -> usize {
+        *candidate_move
+    }
+
+    fn apply_move(&self, candidate_move: &usize) -> Result<Self, ProblemError> {
+        if *candidate_move >= self.problem.number_items {
+            return Err(ProblemError::NewSolutionError(
+                "the item index is out of range.",
+            ));
+        }
+        Ok(self.flip_item(*candidate_move))
     }
 }
 
@@ -219,11 +339,7 @@ impl<'a> GeneticCompatible for KnapsackSolution<'a> {
         .map_err(|_| ProblemError::NewSolutionError("couldn't mutate the knapsack solution."))?;
         let indices = sample(rng, self.problem.number_items, expected_number_flips);
         for n in indices {
-            if self.items.contains(&n) {
-                self.items.remove(&n);
-            } else {
-                self.items.insert(n);
-            }
+            self.toggle_item(n);
         }
         Ok(())
     }
@@ -255,3 +371,255 @@ impl<'a> GeneticCompatible for KnapsackSolution<'a> {
         Ok(children)
     }
 }
+
+/// Construction of knapsack solutions item by item, guided by the value/weight ratios
+impl<'a> ConstructionGraph for KnapsackSolution<'a> {
+    fn number_trails(&self) -> usize {
+        self.problem.number_items
+    }
+
+    fn empty_solution(&self) -> Self {
+        KnapsackSolution {
+            items: HashSet::new(),
+            value: 0.0,
+            weight: 0.0,
+            problem: self.problem,
+        }
+    }
+
+    fn candidate_components(&self) -> Vec<usize> {
+        (0..self.problem.number_items)
+            .filter(|index| !self.items.contains(index))
+            .collect()
+    }
+
+    fn is_feasible(&self, component: usize) -> bool {
+        self.weight + self.problem.all_weights[component] <= self.problem.max_weight
+    }
+
+    fn heuristic(&self, component: usize) -> f64 {
+        self.problem.value_ratio(component)
+    }
+
+    fn trail(&self, component: usize) -> usize {
+        component
+    }
+
+    fn add_component(&self, component: usize) -> Result<Self, ProblemError> {
+        if self.items.contains(&component) {
+            return Err(ProblemError::NewSolutionError(
+                "the item is already in the knapsack.",
+            ));
+        }
+        Ok(self.flip_item(component))
+    }
+}
+
+/// Neighborhoods of the knapsack solutions: single flips, swaps of an item in the knapsack with
+/// an item outside of it, and flips of `move_size` items at once
+impl<'a> VariableNeighborhoodCompatible for KnapsackSolution<'a> {
+    fn number_neighborhoods(&self) -> usize {
+        3
+    }
+
+    fn random_neighbor(
+        &self,
+        neighborhood: usize,
+        move_size: usize,
+        rng: &mut impl Rng,
+    ) -> Result<Self, ProblemError> {
+        let number_items = self.problem.number_items;
+        match neighborhood {
+            0 => Ok(self.flip_item(rng.random_range(0..number_items))),
+            1 => {
+                let inside: Vec<usize> = self.items.iter().cloned().collect();
+                let outside: Vec<usize> = (0..number_items)
+                    .filter(|index| !self.items.contains(index))
+                    .collect();
+                // Without any possible swap, fall back to a single flip
+                if inside.is_empty() || outside.is_empty() {
+                    return Ok(self.flip_item(rng.random_range(0..number_items)));
+                }
+                let removed = inside[rng.random_range(0..inside.len())];
+                let added = outside[rng.random_range(0..outside.len())];
+                Ok(self.flip_items(&[removed, added]))
+            }
+            2 => {
+                let size = move_size.min(number_items);
+                let indices = sample(rng, number_items, size);
+                Ok(self.flip_items(&indices.into_vec()))
+            }
+            _ => Err(ProblemError::NewSolutionError(
+                "the neighborhood index is out of range.",
+            )),
+        }
+    }
+
+    fn neighbors(
+        &self,
+        neighborhood: usize,
+        move_size: usize,
+    ) -> Result<Box<dyn Iterator<Item = Self> + '_>, ProblemError> {
+        let number_items = self.problem.number_items;
+        match neighborhood {
+            0 => Ok(Box::new(
+                (0..number_items).map(|index| self.flip_item(index)),
+            )),
+            1 => Ok(Box::new(self.items.iter().flat_map(move |&removed| {
+                (0..number_items)
+                    .filter(|index| !self.items.contains(index))
+                    .map(move |added| self.flip_items(&[removed, added]))
+            }))),
+            2 => {
+                let size = move_size.min(number_items);
+                Ok(Box::new(
+                    Combinations::new(number_items, size).map(|indices| self.flip_items(&indices)),
+                ))
+            }
+            _ => Err(ProblemError::NewSolutionError(
+                "the neighborhood index is out of range.",
+            )),
+        }
+    }
+}
+
+/// Lazy enumeration of the combinations of `size` items among `number_items`, in lexicographic
+/// order
+struct Combinations {
+    /// Next combination, if any
+    indices: Option<Vec<usize>>,
+    /// Total number of items
+    number_items: usize,
+}
+
+impl Combinations {
+    fn new(number_items: usize, size: usize) -> Self {
+        Combinations {
+            indices: (size <= number_items).then(|| (0..size).collect()),
+            number_items,
+        }
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let current = self.indices.take()?;
+        let size = current.len();
+        // Increment the last index which has not reached its maximum, then reset the next ones
+        if let Some(position) = (0..size)
+            .rev()
+            .find(|&i| current[i] < self.number_items - size + i)
+        {
+            let mut next = current.clone();
+            next[position] += 1;
+            for i in position + 1..size {
+                next[i] = next[i - 1] + 1;
+            }
+            self.indices = Some(next);
+        }
+        Some(current)
+    }
+}
+
+/// Destroy operator removing random items from the knapsack
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnapsackRandomRemoval {
+    /// Number of items removed
+    pub number_removed: usize,
+}
+
+impl<'a> DestroyOperator<KnapsackSolution<'a>> for KnapsackRandomRemoval {
+    fn destroy(
+        &self,
+        solution: &KnapsackSolution<'a>,
+        rng: &mut dyn RngCore,
+    ) -> Result<KnapsackSolution<'a>, ProblemError> {
+        let items: Vec<usize> = solution.items.iter().cloned().collect();
+        let removed: Vec<usize> = sample(rng, items.len(), self.number_removed.min(items.len()))
+            .into_iter()
+            .map(|position| items[position])
+            .collect();
+        Ok(solution.flip_items(&removed))
+    }
+}
+
+/// Destroy operator removing the items with the lowest value ratio from the knapsack
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnapsackWorstRatioRemoval {
+    /// Number of items removed
+    pub number_removed: usize,
+}
+
+impl<'a> DestroyOperator<KnapsackSolution<'a>> for KnapsackWorstRatioRemoval {
+    fn destroy(
+        &self,
+        solution: &KnapsackSolution<'a>,
+        _rng: &mut dyn RngCore,
+    ) -> Result<KnapsackSolution<'a>, ProblemError> {
+        let mut items: Vec<usize> = solution.items.iter().cloned().collect();
+        items.sort_by(|&a, &b| {
+            solution
+                .problem
+                .value_ratio(a)
+                .partial_cmp(&solution.problem.value_ratio(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        items.truncate(self.number_removed);
+        Ok(solution.flip_items(&items))
+    }
+}
+
+/// Repair operator adding the items which fit by decreasing value ratio
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnapsackGreedyRepair;
+
+impl<'a> RepairOperator<KnapsackSolution<'a>> for KnapsackGreedyRepair {
+    fn repair(
+        &self,
+        solution: &KnapsackSolution<'a>,
+        _rng: &mut dyn RngCore,
+    ) -> Result<KnapsackSolution<'a>, ProblemError> {
+        Ok(solution.greedy_completion(None))
+    }
+}
+
+/// Repair operator adding first the item with the largest regret, the value lost by the greedy
+/// completion of the knapsack if this item is left out
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnapsackRegretRepair;
+
+impl<'a> RepairOperator<KnapsackSolution<'a>> for KnapsackRegretRepair {
+    fn repair(
+        &self,
+        solution: &KnapsackSolution<'a>,
+        _rng: &mut dyn RngCore,
+    ) -> Result<KnapsackSolution<'a>, ProblemError> {
+        let mut current_solution = solution.clone();
+        loop {
+            let candidates: Vec<usize> = current_solution
+                .candidate_components()
+                .into_iter()
+                .filter(|&index| current_solution.is_feasible(index))
+                .collect();
+            let best_candidate = candidates
+                .into_iter()
+                .map(|index| {
+                    let with_item = current_solution.flip_item(index).greedy_completion(None);
+                    let without_item = current_solution.greedy_completion(Some(index));
+                    (index, with_item.value - without_item.value, with_item.value)
+                })
+                .max_by(|a, b| {
+                    (a.1, a.2)
+                        .partial_cmp(&(b.1, b.2))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+            match best_candidate {
+                Some((index, _, _)) => current_solution = current_solution.flip_item(index),
+                None => break,
+            }
+        }
+        Ok(current_solution)
+    }
+}
@@ -1,7 +1,14 @@
 mod common;
+mod continuous;
 mod errors;
 mod knapsack;
 
-pub use crate::problems::common::ProblemSolution;
+pub use crate::problems::common::{
+    BinaryVectorSolution, MultiObjectiveSolution, ProblemSolution, RealVectorSolution,
+};
+pub use crate::problems::continuous::{BenchmarkFunction, ContinuousProblem, ContinuousSolution};
 pub use crate::problems::errors::ProblemError;
-pub use crate::problems::knapsack::{KnapsackProblem, KnapsackSolution};
+pub use crate::problems::knapsack::{
+    KnapsackGreedyRepair, KnapsackProblem, KnapsackRandomRemoval, KnapsackRegretRepair,
+    KnapsackSolution, KnapsackWorstRatioRemoval,
+};
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        AdaptiveLargeNeighborhoodSearch, AdaptiveLargeNeighborhoodSearchConfig,
+    };
+    use optimization_metaheuristics::problems::{
+        KnapsackGreedyRepair, KnapsackProblem, KnapsackRandomRemoval, KnapsackRegretRepair,
+        KnapsackSolution, KnapsackWorstRatioRemoval,
+    };
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_adaptive_large_neighborhood_search() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = AdaptiveLargeNeighborhoodSearchConfig {
+                max_iterations: 200,
+                segment_length: 20,
+                ..Default::default()
+            };
+            let mut alns = AdaptiveLargeNeighborhoodSearch::new(config);
+            alns.register_destroy_operator(KnapsackRandomRemoval { number_removed: 2 });
+            alns.register_destroy_operator(KnapsackWorstRatioRemoval { number_removed: 1 });
+            alns.register_repair_operator(KnapsackGreedyRepair);
+            alns.register_repair_operator(KnapsackRegretRepair);
+            let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+            let result = alns.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+
+    #[test]
+    fn test_knapsack_adaptive_large_neighborhood_search_operators() {
+        let problem =
+            KnapsackProblem::new(&[10.0, 6.0, 6.0], &[10.0, 5.0, 5.0], 10.0, Some(12.0)).unwrap();
+        let mut rng = SmallRng::seed_from_u64(654321);
+        let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+
+        // Operators are required
+        let mut alns = AdaptiveLargeNeighborhoodSearch::new(Default::default());
+        assert!(alns.execute(initial_solution.clone(), &mut rng).is_err());
+
+        // The regret repair sees that the heavy item blocks the two others
+        alns.register_destroy_operator(KnapsackWorstRatioRemoval { number_removed: 1 });
+        alns.register_repair_operator(KnapsackRegretRepair);
+        let result = alns.execute(initial_solution, &mut rng).unwrap();
+        assert_eq!(result.solution.value, 12.0);
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        AntColonyAlgorithm, AntColonyConfig, AntColonyVariant,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_ant_colony() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let variants = [
+            AntColonyVariant::AntSystem,
+            AntColonyVariant::MaxMinAntSystem {
+                min_max_ratio: 0.01,
+            },
+            AntColonyVariant::AntColonySystem {
+                exploitation_probability: 0.5,
+                local_evaporation_rate: 0.1,
+            },
+        ];
+        for problem in problems {
+            for variant in variants {
+                let mut rng = SmallRng::seed_from_u64(654321);
+                let config = AntColonyConfig {
+                    variant,
+                    ..Default::default()
+                };
+                let aco = AntColonyAlgorithm::new(config);
+                let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+                let result = aco.execute(initial_solution, &mut rng).unwrap();
+                assert!(
+                    result.solution.value == problem.optimal_value.unwrap(),
+                    "Expected {}, found {} with {:?}.",
+                    problem.optimal_value.unwrap(),
+                    result.solution.value,
+                    variant
+                )
+            }
+        }
+    }
+}
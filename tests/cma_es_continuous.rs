@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{CmaEsAlgorithm, CmaEsConfig, CmaEsRestart};
+    use optimization_metaheuristics::problems::{
+        BenchmarkFunction, ContinuousProblem, ContinuousSolution,
+    };
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn test_continuous_cma_es() {
+        let problems = [
+            ContinuousProblem::new(BenchmarkFunction::Sphere, &[(-5.0, 5.0); 5]).unwrap(),
+            ContinuousProblem::new(BenchmarkFunction::Rosenbrock, &[(-2.0, 2.0); 4]).unwrap(),
+            ContinuousProblem::new(BenchmarkFunction::Rastrigin, &[(-5.12, 5.12); 2]).unwrap(),
+        ];
+        let restart_strategies = [
+            CmaEsRestart::Ipop {
+                increase_factor: 2.0,
+            },
+            CmaEsRestart::Bipop,
+        ];
+        for problem in problems.iter() {
+            for restart_strategy in restart_strategies {
+                let mut rng = SmallRng::seed_from_u64(654321);
+                let config = CmaEsConfig {
+                    restart_strategy,
+                    stop_threshold: Some(1e-8),
+                    ..Default::default()
+                };
+                let cma_es = CmaEsAlgorithm::new(config);
+                let initial_solution = ContinuousSolution::new_random(problem, &mut rng).unwrap();
+                let result = cma_es.execute(initial_solution, &mut rng).unwrap();
+                assert!(
+                    (result.solution.value - problem.optimal_value.unwrap()).abs() < 1e-6,
+                    "Expected {}, found {} with {:?}.",
+                    problem.optimal_value.unwrap(),
+                    result.solution.value,
+                    restart_strategy
+                )
+            }
+        }
+    }
+}
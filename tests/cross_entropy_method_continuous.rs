@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{CrossEntropyMethod, CrossEntropyMethodConfig};
+    use optimization_metaheuristics::problems::{
+        BenchmarkFunction, ContinuousProblem, ContinuousSolution,
+    };
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn test_continuous_cross_entropy_method() {
+        let problems = [
+            ContinuousProblem::new(BenchmarkFunction::Sphere, &[(-5.0, 5.0); 5]).unwrap(),
+            ContinuousProblem::new(BenchmarkFunction::Rastrigin, &[(-5.12, 5.12); 2]).unwrap(),
+        ];
+        for problem in problems.iter() {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config =
+                CrossEntropyMethodConfig::new(500, 100, 0.1, 0.7, 0.3, Some(1e-8)).unwrap();
+            let cem = CrossEntropyMethod::new(config);
+            let initial_solution = ContinuousSolution::new_random(problem, &mut rng).unwrap();
+            let result = cem.execute_continuous(initial_solution, &mut rng).unwrap();
+            assert!(
+                (result.solution.value - problem.optimal_value.unwrap()).abs() < 1e-6,
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{CrossEntropyMethod, CrossEntropyMethodConfig};
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_cross_entropy_method() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = CrossEntropyMethodConfig::new(100, 100, 0.1, 0.7, 0.3, None).unwrap();
+            let cem = CrossEntropyMethod::new(config);
+            let initial_solution = KnapsackSolution::new_random(None, &problem, &mut rng).unwrap();
+            let result = cem.execute_discrete(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        DifferentialEvolution, DifferentialEvolutionConfig, DifferentialEvolutionStrategy,
+        ParameterAdaptation,
+    };
+    use optimization_metaheuristics::problems::{
+        BenchmarkFunction, ContinuousProblem, ContinuousSolution,
+    };
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn test_continuous_differential_evolution() {
+        let problems = [
+            ContinuousProblem::new(BenchmarkFunction::Sphere, &[(-5.0, 5.0); 5]).unwrap(),
+            ContinuousProblem::new(BenchmarkFunction::Rosenbrock, &[(-2.0, 2.0); 2]).unwrap(),
+            ContinuousProblem::new(BenchmarkFunction::Ackley, &[(-5.0, 5.0); 3]).unwrap(),
+        ];
+        let strategies = [
+            DifferentialEvolutionStrategy::Rand1Bin,
+            DifferentialEvolutionStrategy::Best1Bin,
+            DifferentialEvolutionStrategy::CurrentToBest1Bin,
+            DifferentialEvolutionStrategy::Rand2Exp,
+        ];
+        let adaptations = [
+            ParameterAdaptation::Fixed,
+            ParameterAdaptation::JDE {
+                tau_scaling_factor: 0.1,
+                tau_crossover_rate: 0.1,
+            },
+            ParameterAdaptation::Shade { memory_size: 5 },
+        ];
+        for problem in problems.iter() {
+            for strategy in strategies {
+                for adaptation in adaptations {
+                    let mut rng = SmallRng::seed_from_u64(654321);
+                    let config = DifferentialEvolutionConfig::new(
+                        500, 30, 0.5, 0.9, strategy, adaptation, None,
+                    )
+                    .unwrap();
+                    let de = DifferentialEvolution::new(config);
+                    let initial_solution =
+                        ContinuousSolution::new_random(problem, &mut rng).unwrap();
+                    let result = de.execute(initial_solution, &mut rng).unwrap();
+                    assert!(
+                        (result.solution.value - problem.optimal_value.unwrap()).abs() < 1e-4,
+                        "Expected {}, found {} with {:?} and {:?}.",
+                        problem.optimal_value.unwrap(),
+                        result.solution.value,
+                        strategy,
+                        adaptation
+                    )
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_continuous_differential_evolution_empty_memory() {
+        // Struct literals bypass the validation of the constructor
+        let problem = ContinuousProblem::new(BenchmarkFunction::Sphere, &[(-5.0, 5.0); 2]).unwrap();
+        let mut rng = SmallRng::seed_from_u64(654321);
+        let config = DifferentialEvolutionConfig {
+            adaptation: ParameterAdaptation::Shade { memory_size: 0 },
+            ..Default::default()
+        };
+        let de = DifferentialEvolution::new(config);
+        let initial_solution = ContinuousSolution::new_random(&problem, &mut rng).unwrap();
+        assert!(de.execute(initial_solution, &mut rng).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        CompactGeneticAlgorithm, CompactGeneticAlgorithmConfig, Pbil, PbilConfig, Umda, UmdaConfig,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_pbil() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = PbilConfig::new(300, 50, 0.1, 0.075, 0.02, 0.05, None).unwrap();
+            let pbil = Pbil::new(config);
+            let initial_solution = KnapsackSolution::new_random(None, &problem, &mut rng).unwrap();
+            let result = pbil.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+
+    #[test]
+    fn test_knapsack_umda() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = UmdaConfig::new(300, 100, 30, None).unwrap();
+            let umda = Umda::new(config);
+            let initial_solution = KnapsackSolution::new_random(None, &problem, &mut rng).unwrap();
+            let result = umda.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+
+    #[test]
+    fn test_knapsack_compact_genetic_algorithm() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = CompactGeneticAlgorithmConfig::new(20_000, 100, None).unwrap();
+            let cga = CompactGeneticAlgorithm::new(config);
+            let initial_solution = KnapsackSolution::new_random(None, &problem, &mut rng).unwrap();
+            let result = cga.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+
+    #[test]
+    fn test_knapsack_umda_short_bit_strings() {
+        let problems = [
+            KnapsackProblem::new(&[5.0], &[1.0], 2.0, Some(5.0)).unwrap(),
+            KnapsackProblem::new(&[5.0, 3.0], &[2.0, 1.0], 2.0, Some(5.0)).unwrap(),
+        ];
+        for problem in problems.iter() {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = UmdaConfig::new(20, 10, 5, None).unwrap();
+            let umda = Umda::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], problem).unwrap();
+            let result = umda.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+}
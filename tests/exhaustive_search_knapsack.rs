@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{ExhaustiveSearch, ExhaustiveSearchConfig};
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_exhaustive_search() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let exhaustive_search = ExhaustiveSearch::new(ExhaustiveSearchConfig::default());
+            assert!(exhaustive_search.is_exhaustive(problem.number_items));
+            let initial_solution = KnapsackSolution::new(Vec::new(), &problem).unwrap();
+            let result = exhaustive_search.execute(initial_solution).unwrap();
+            assert_eq!(result.number_iterations, 1 << problem.number_items);
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+}
@@ -1,11 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use optimization_metaheuristics::algorithms::{GeneticAlgorithm, GeneticAlgorithmConfig};
+    use optimization_metaheuristics::algorithms::{
+        GeneticAlgorithm, GeneticAlgorithmConfig, LinearRankSelection, RandomSelection,
+        ReplacementStrategy, RouletteWheelSelection, SelectionOperator,
+        StochasticUniversalSampling, TournamentSelection,
+    };
     use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
     use std::fs;
     use std::io;
+    use std::sync::Arc;
 
     #[test]
     fn test_knapsack_genetic_algorithm() {
@@ -35,4 +40,128 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn test_knapsack_genetic_algorithm_selection_operators() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let selection_operators: Vec<Arc<dyn SelectionOperator>> = vec![
+            Arc::new(TournamentSelection::new(3).unwrap()),
+            Arc::new(RouletteWheelSelection),
+            Arc::new(LinearRankSelection::new(1.5).unwrap()),
+            Arc::new(StochasticUniversalSampling),
+            Arc::new(RandomSelection),
+        ];
+        for problem in problems {
+            for selection_operator in selection_operators.iter() {
+                let mut rng = SmallRng::seed_from_u64(654321);
+                let config = GeneticAlgorithmConfig {
+                    selection_operator: selection_operator.clone(),
+                    ..GeneticAlgorithmConfig::new(1000, 100, 0.2, 4, None).unwrap()
+                };
+                let ga = GeneticAlgorithm::new(config);
+                let initial_solutions = (1..ga.config.population_size)
+                    .map(|_| KnapsackSolution::new_random(None, &problem, &mut rng).unwrap())
+                    .collect();
+                let result = ga.execute(initial_solutions, &mut rng).unwrap();
+                assert!(
+                    result.solution.value == problem.optimal_value.unwrap(),
+                    "Expected {}, found {} with {:?}.",
+                    problem.optimal_value.unwrap(),
+                    result.solution.value,
+                    selection_operator
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn test_knapsack_genetic_algorithm_replacement_strategies() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        // Generational strategies need as many offsprings as individuals in the population
+        let replacement_strategies = [
+            (ReplacementStrategy::Generational { elitism: 2 }, 20),
+            (ReplacementStrategy::MuCommaLambda, 20),
+            (ReplacementStrategy::SteadyStateReplaceWorst, 4),
+            (ReplacementStrategy::AgeBased, 4),
+        ];
+        for problem in problems {
+            for (replacement_strategy, number_pairs_parents) in replacement_strategies {
+                let mut rng = SmallRng::seed_from_u64(654321);
+                let config = GeneticAlgorithmConfig {
+                    selection_operator: Arc::new(TournamentSelection::new(2).unwrap()),
+                    replacement_strategy,
+                    ..GeneticAlgorithmConfig::new(200, 40, 0.2, number_pairs_parents, None).unwrap()
+                };
+                let ga = GeneticAlgorithm::new(config);
+                let initial_solutions = (0..ga.config.population_size)
+                    .map(|_| KnapsackSolution::new_random(None, &problem, &mut rng).unwrap())
+                    .collect();
+                let result = ga.execute(initial_solutions, &mut rng).unwrap();
+                assert!(
+                    result.solution.value == problem.optimal_value.unwrap(),
+                    "Expected {}, found {} with {:?}.",
+                    problem.optimal_value.unwrap(),
+                    result.solution.value,
+                    replacement_strategy
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn test_genetic_algorithm_replacement_feasibility() {
+        // 8 offsprings cannot replace a population of 40 individuals
+        let config = GeneticAlgorithmConfig::new(200, 40, 0.2, 4, None).unwrap();
+        assert!(
+            config
+                .clone()
+                .with_replacement_strategy(ReplacementStrategy::MuCommaLambda)
+                .is_err()
+        );
+        assert!(
+            config
+                .clone()
+                .with_replacement_strategy(ReplacementStrategy::Generational { elitism: 2 })
+                .is_err()
+        );
+        assert!(
+            config
+                .with_replacement_strategy(ReplacementStrategy::SteadyStateReplaceWorst)
+                .is_ok()
+        );
+        // More parents than individuals are rejected, whatever the strategy
+        assert!(GeneticAlgorithmConfig::new(100, 2, 0.1, 5, None).is_err());
+        let config = GeneticAlgorithmConfig {
+            number_pairs_parents: 30,
+            ..GeneticAlgorithmConfig::new(200, 40, 0.2, 20, None).unwrap()
+        };
+        for replacement_strategy in [
+            ReplacementStrategy::MuPlusLambda,
+            ReplacementStrategy::MuCommaLambda,
+            ReplacementStrategy::Generational { elitism: 0 },
+        ] {
+            assert!(
+                config
+                    .clone()
+                    .with_replacement_strategy(replacement_strategy)
+                    .is_err()
+            );
+        }
+    }
 }
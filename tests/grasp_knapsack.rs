@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        Grasp, GraspConfig, HillClimbingConfig, ReactiveAlpha, RestrictedCandidateList,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_grasp() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let variants = [
+            (RestrictedCandidateList::Value { alpha: 0.3 }, None),
+            (RestrictedCandidateList::Cardinality { size: 3 }, None),
+            (
+                RestrictedCandidateList::Value { alpha: 0.0 },
+                Some(ReactiveAlpha::new(vec![0.0, 0.25, 0.5, 1.0], 10, 5.0).unwrap()),
+            ),
+        ];
+        for (problem, (restricted_candidate_list, reactive_alpha)) in problems
+            .iter()
+            .flat_map(|problem| variants.iter().map(move |v| (problem, v)))
+        {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = GraspConfig::new(
+                100,
+                *restricted_candidate_list,
+                reactive_alpha.clone(),
+                HillClimbingConfig::default(),
+                None,
+            )
+            .unwrap();
+            let grasp = Grasp::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], problem).unwrap();
+            let result = grasp.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {} with {:?}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value,
+                restricted_candidate_list
+            )
+        }
+    }
+}
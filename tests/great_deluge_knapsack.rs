@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{GreatDelugeAlgorithm, GreatDelugeConfig};
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_great_deluge() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = GreatDelugeConfig::new(1000, Some(50.0), 0.1, None).unwrap();
+            let algorithm = GreatDelugeAlgorithm::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+            let result = algorithm.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+}
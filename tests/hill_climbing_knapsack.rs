@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        HillClimbingAlgorithm, HillClimbingConfig, ImprovementStrategy,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_hill_climbing() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let strategies = [
+            ImprovementStrategy::FirstImprovement,
+            ImprovementStrategy::BestImprovement,
+        ];
+        for (problem, strategy) in problems
+            .iter()
+            .flat_map(|problem| strategies.iter().map(move |s| (problem, *s)))
+        {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = HillClimbingConfig::new(2000, 20, strategy, 50, None).unwrap();
+            let hc = HillClimbingAlgorithm::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], problem).unwrap();
+            let result = hc.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {} with {:?}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value,
+                strategy
+            )
+        }
+    }
+
+    #[test]
+    fn test_knapsack_hill_climbing_local_optimum() {
+        // Without restarts, the climb stops at the first local optimum
+        let problem =
+            KnapsackProblem::new(&[10.0, 6.0, 6.0], &[10.0, 5.0, 5.0], 10.0, Some(12.0)).unwrap();
+        let mut rng = SmallRng::seed_from_u64(654321);
+        let config = HillClimbingConfig {
+            improvement_strategy: ImprovementStrategy::BestImprovement,
+            ..Default::default()
+        };
+        let hc = HillClimbingAlgorithm::new(config);
+        let initial_solution = KnapsackSolution::new(vec![0], &problem).unwrap();
+        let result = hc.execute(initial_solution, &mut rng).unwrap();
+        assert_eq!(result.solution.value, 10.0);
+        assert_eq!(result.number_restarts(), 0);
+    }
+}
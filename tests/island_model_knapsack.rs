@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        EmigrantPolicy, GeneticAlgorithmConfig, ImmigrantPolicy, IslandModel, IslandModelConfig,
+        MigrationTopology,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_island_model() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let migrations = [
+            (
+                MigrationTopology::Ring,
+                EmigrantPolicy::Best,
+                ImmigrantPolicy::ReplaceWorst,
+            ),
+            (
+                MigrationTopology::FullyConnected,
+                EmigrantPolicy::Random,
+                ImmigrantPolicy::ReplaceRandom,
+            ),
+            (
+                MigrationTopology::Random,
+                EmigrantPolicy::Best,
+                ImmigrantPolicy::ReplaceRandom,
+            ),
+        ];
+        for (problem, (topology, emigrant_policy, immigrant_policy)) in problems
+            .iter()
+            .flat_map(|problem| migrations.iter().map(move |m| (problem, *m)))
+        {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let genetic_algorithm = GeneticAlgorithmConfig::new(200, 25, 0.2, 4, None).unwrap();
+            let config = IslandModelConfig::new(
+                4,
+                genetic_algorithm,
+                10,
+                2,
+                topology,
+                emigrant_policy,
+                immigrant_policy,
+            )
+            .unwrap();
+            let island_model = IslandModel::new(config);
+            let initial_solutions = (0..100)
+                .map(|_| KnapsackSolution::new_random(None, problem, &mut rng).unwrap())
+                .collect();
+            let result = island_model.execute(initial_solutions, &mut rng).unwrap();
+            assert_eq!(result.number_generations, 200);
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {} with {:?}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value,
+                topology
+            )
+        }
+    }
+
+    #[test]
+    fn test_island_model_immigrants_validation() {
+        // Three islands sending 10 individuals each would replace a population of 25
+        let genetic_algorithm = GeneticAlgorithmConfig::new(200, 25, 0.2, 4, None).unwrap();
+        let config = |topology| {
+            IslandModelConfig::new(
+                4,
+                genetic_algorithm.clone(),
+                10,
+                10,
+                topology,
+                EmigrantPolicy::Best,
+                ImmigrantPolicy::ReplaceWorst,
+            )
+        };
+        assert!(config(MigrationTopology::Ring).is_ok());
+        assert!(config(MigrationTopology::FullyConnected).is_err());
+        assert!(config(MigrationTopology::Random).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        AcceptanceCriterion, HillClimbingConfig, IteratedLocalSearchAlgorithm,
+        IteratedLocalSearchConfig,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_iterated_local_search() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let criteria = [
+            AcceptanceCriterion::Better,
+            AcceptanceCriterion::RandomWalk,
+            AcceptanceCriterion::Restart {
+                stall_iterations: 10,
+            },
+        ];
+        for (problem, criterion) in problems
+            .iter()
+            .flat_map(|problem| criteria.iter().map(move |c| (problem, *c)))
+        {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = IteratedLocalSearchConfig::new(
+                200,
+                2,
+                HillClimbingConfig::default(),
+                criterion,
+                None,
+            )
+            .unwrap();
+            let ils = IteratedLocalSearchAlgorithm::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], problem).unwrap();
+            let result = ils.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {} with {:?}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value,
+                criterion
+            );
+            if let AcceptanceCriterion::Restart { .. } = criterion {
+                assert!(result.number_restarts() > 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        GeneticAlgorithmConfig, HillClimbingConfig, ImprovementStrategy, LearningMode,
+        LocalImprovement, MemeticAlgorithm, MemeticAlgorithmConfig, SimulatedAnnealingConfig,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_memetic_algorithm() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let local_improvements = [
+            LocalImprovement::HillClimbing(
+                HillClimbingConfig::new(50, 10, ImprovementStrategy::FirstImprovement, 0, None)
+                    .unwrap(),
+            ),
+            LocalImprovement::SimulatedAnnealing(
+                SimulatedAnnealingConfig::new(50, 10.0, 0.1, 0.9, None).unwrap(),
+            ),
+        ];
+        for problem in problems {
+            for local_improvement in local_improvements.iter() {
+                for learning_mode in [LearningMode::Lamarckian, LearningMode::Baldwinian] {
+                    let mut rng = SmallRng::seed_from_u64(654321);
+                    let config = MemeticAlgorithmConfig::new(
+                        GeneticAlgorithmConfig::new(100, 40, 0.2, 4, None).unwrap(),
+                        local_improvement.clone(),
+                        learning_mode,
+                        0.5,
+                    )
+                    .unwrap();
+                    let memetic = MemeticAlgorithm::new(config);
+                    let initial_solutions = (0..40)
+                        .map(|_| KnapsackSolution::new_random(None, &problem, &mut rng).unwrap())
+                        .collect();
+                    let result = memetic.execute(initial_solutions, &mut rng).unwrap();
+                    assert!(
+                        result.solution.value == problem.optimal_value.unwrap(),
+                        "Expected {}, found {} with {:?} and {:?}.",
+                        problem.optimal_value.unwrap(),
+                        result.solution.value,
+                        local_improvement,
+                        learning_mode
+                    )
+                }
+            }
+        }
+    }
+}
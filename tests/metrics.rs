@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::metrics::{
+        epsilon_indicator, exact_hypervolume, generational_distance, hypervolume,
+        inverted_generational_distance, inverted_generational_distance_plus,
+        monte_carlo_hypervolume, spread,
+    };
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    const TOLERANCE: f64 = 1e-9;
+
+    #[test]
+    fn test_hypervolume() {
+        let mut rng = SmallRng::seed_from_u64(123456);
+
+        // Staircase in two dimensions, with a dominated point and a point outside the reference box
+        let points = [
+            vec![1.0, 3.0],
+            vec![2.0, 2.0],
+            vec![3.0, 1.0],
+            vec![3.0, 3.0],
+            vec![5.0, 0.0],
+        ];
+        let volume = exact_hypervolume(&points, &[4.0, 4.0]).unwrap();
+        assert!((volume - 6.0).abs() < TOLERANCE, "Found {}.", volume);
+
+        // Two overlapping boxes in three dimensions
+        let points = [vec![0.0, 0.0, 1.0], vec![1.0, 1.0, 0.0]];
+        let volume = exact_hypervolume(&points, &[2.0, 2.0, 2.0]).unwrap();
+        assert!((volume - 5.0).abs() < TOLERANCE, "Found {}.", volume);
+
+        // The estimate agrees with the exact value on random fronts
+        for dimension in [3, 5] {
+            let points: Vec<Vec<f64>> = (0..20)
+                .map(|_| (0..dimension).map(|_| rng.random::<f64>()).collect())
+                .collect();
+            let reference_point = vec![1.1; dimension];
+            let exact = exact_hypervolume(&points, &reference_point).unwrap();
+            let estimate =
+                monte_carlo_hypervolume(&points, &reference_point, 200_000, &mut rng).unwrap();
+            assert!(
+                (exact - estimate).abs() < 0.02 * exact,
+                "Expected {}, found {}.",
+                exact,
+                estimate
+            );
+        }
+
+        // A single point in many objectives is estimated
+        let points = [vec![0.5; 8]];
+        let estimate = hypervolume(&points, &[1.0; 8], &mut rng).unwrap();
+        assert!(
+            (estimate - 0.5f64.powi(8)).abs() < 1e-2,
+            "Found {}.",
+            estimate
+        );
+
+        // Invalid inputs
+        assert!(exact_hypervolume(&[], &[1.0, 1.0]).is_err());
+        assert!(exact_hypervolume(&[vec![0.0, 0.0]], &[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_distance_indicators() {
+        let reference_front = [vec![0.0, 1.0], vec![0.5, 0.5], vec![1.0, 0.0]];
+        let front = [vec![0.0, 2.0], vec![1.0, 1.0], vec![2.0, 0.0]];
+
+        // The middle points are closer than the extreme ones
+        let expected = (1.0 + 0.5f64.sqrt() + 1.0) / 3.0;
+        let gd = generational_distance(&front, &reference_front).unwrap();
+        assert!((gd - expected).abs() < TOLERANCE, "Found {}.", gd);
+        let igd = inverted_generational_distance(&front, &reference_front).unwrap();
+        assert!((igd - expected).abs() < TOLERANCE, "Found {}.", igd);
+        let igd_plus = inverted_generational_distance_plus(&front, &reference_front).unwrap();
+        assert!(
+            (igd_plus - expected).abs() < TOLERANCE,
+            "Found {}.",
+            igd_plus
+        );
+        let epsilon = epsilon_indicator(&front, &reference_front).unwrap();
+        assert!((epsilon - 1.0).abs() < TOLERANCE, "Found {}.", epsilon);
+
+        // The reference front is perfect for itself
+        for indicator in [
+            generational_distance,
+            inverted_generational_distance,
+            inverted_generational_distance_plus,
+            epsilon_indicator,
+        ] {
+            let value = indicator(&reference_front, &reference_front).unwrap();
+            assert!(value.abs() < TOLERANCE, "Found {}.", value);
+        }
+
+        // A dominating front has no IGD+ penalty
+        let better_front = [vec![-1.0, -1.0]];
+        let igd_plus =
+            inverted_generational_distance_plus(&better_front, &reference_front).unwrap();
+        assert!(igd_plus.abs() < TOLERANCE, "Found {}.", igd_plus);
+        assert!(generational_distance(&front, &[vec![0.0, 0.0, 0.0]]).is_err());
+    }
+
+    #[test]
+    fn test_spread() {
+        let reference_front: Vec<Vec<f64>> = (0..=10)
+            .map(|i| vec![i as f64 / 10.0, 1.0 - i as f64 / 10.0])
+            .collect();
+
+        // A uniform front reaching the extremes has no spread
+        let uniform = spread(&reference_front, &reference_front).unwrap();
+        assert!(uniform.abs() < TOLERANCE, "Found {}.", uniform);
+
+        // A clustered front is worse
+        let clustered = [vec![0.4, 0.6], vec![0.45, 0.55], vec![0.5, 0.5]];
+        let value = spread(&clustered, &reference_front).unwrap();
+        assert!(value > 0.5, "Found {}.", value);
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{Moead, MoeadConfig, Scalarization, dominates};
+    use optimization_metaheuristics::problems::{
+        KnapsackProblem, KnapsackSolution, MultiObjectiveSolution,
+    };
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_moead() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let scalarizations = [Scalarization::Tchebycheff, Scalarization::WeightedSum];
+        for (problem, scalarization) in problems
+            .iter()
+            .flat_map(|problem| scalarizations.iter().map(move |s| (problem, *s)))
+        {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = MoeadConfig::new(200, 60, 10, 2, 0.2, scalarization, None).unwrap();
+            let moead = Moead::new(config);
+            let initial_solutions = (0..moead.config.population_size)
+                .map(|_| KnapsackSolution::new_random(None, problem, &mut rng).unwrap())
+                .collect();
+            let result = moead.execute(initial_solutions, &mut rng).unwrap();
+
+            // The front is mutually non-dominated and reaches the single-objective optimum
+            for first in result.pareto_front.iter() {
+                for second in result.pareto_front.iter() {
+                    assert!(!dominates(&first.objectives(), &second.objectives()));
+                }
+            }
+            let best_value = result
+                .pareto_front
+                .iter()
+                .map(|solution| solution.value)
+                .fold(f64::NEG_INFINITY, f64::max);
+            assert!(
+                best_value == problem.optimal_value.unwrap(),
+                "Expected {}, found {} with {:?}.",
+                problem.optimal_value.unwrap(),
+                best_value,
+                scalarization
+            )
+        }
+    }
+
+    #[test]
+    fn test_moead_weight_vectors() {
+        let config =
+            MoeadConfig::new(200, 60, 10, 2, 0.2, Scalarization::Tchebycheff, None).unwrap();
+        let moead = Moead::new(config);
+
+        // Two objectives use evenly spaced weights, one per individual
+        let weights = moead.weight_vectors(2);
+        assert_eq!(weights.len(), 60);
+        assert!(weights.contains(&vec![1.0, 0.0]) && weights.contains(&vec![0.0, 1.0]));
+
+        // Three objectives use the largest lattice fitting the population, 9 divisions here
+        let weights = moead.weight_vectors(3);
+        assert_eq!(weights.len(), 55);
+        for weight in weights.iter() {
+            assert!((weight.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+            assert!(
+                weight
+                    .iter()
+                    .all(|w| (w * 9.0 - (w * 9.0).round()).abs() < 1e-9)
+            );
+        }
+        for (i, first) in weights.iter().enumerate() {
+            assert!(weights[i + 1..].iter().all(|second| first != second));
+        }
+    }
+}
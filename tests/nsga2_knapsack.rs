@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        Nsga2, Nsga2Config, crowding_distances, dominates,
+    };
+    use optimization_metaheuristics::problems::{
+        KnapsackProblem, KnapsackSolution, MultiObjectiveSolution,
+    };
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_nsga2() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = Nsga2Config::new(200, 60, 0.2, None).unwrap();
+            let nsga2 = Nsga2::new(config);
+            let initial_solutions = (0..nsga2.config.population_size)
+                .map(|_| KnapsackSolution::new_random(None, &problem, &mut rng).unwrap())
+                .collect();
+            let result = nsga2.execute(initial_solutions, &mut rng).unwrap();
+
+            // The front is mutually non-dominated and reaches the single-objective optimum
+            for first in result.pareto_front.iter() {
+                for second in result.pareto_front.iter() {
+                    assert!(!dominates(&first.objectives(), &second.objectives()));
+                }
+            }
+            let best_value = result
+                .pareto_front
+                .iter()
+                .map(|solution| solution.value)
+                .fold(f64::NEG_INFINITY, f64::max);
+            assert!(
+                best_value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                best_value
+            );
+
+            // The crowding distance of the boundary solutions is infinite, so the other end of
+            // the front, the empty knapsack, survives the environmental selection
+            let objectives: Vec<Vec<f64>> = result
+                .pareto_front
+                .iter()
+                .map(|solution| solution.objectives())
+                .collect();
+            let front: Vec<usize> = (0..objectives.len()).collect();
+            let distances = crowding_distances(&objectives, &front);
+            let empty = objectives.iter().position(|o| o[1] == 0.0).unwrap();
+            assert!(distances[empty].is_infinite());
+        }
+    }
+
+    #[test]
+    fn test_knapsack_nsga2_secondary_weights() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .map(|problem| {
+                // Trade the value against a second weight dimension instead of the item count
+                let secondary_weights: Vec<f64> =
+                    problem.all_weights.iter().rev().cloned().collect();
+                assert!(problem.clone().with_secondary_weights(&[1.0]).is_err());
+                let problem = problem.with_secondary_weights(&secondary_weights).unwrap();
+                assert_eq!(problem.secondary_weights(), secondary_weights.as_slice());
+                problem
+            })
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = Nsga2Config::new(200, 60, 0.2, None).unwrap();
+            let nsga2 = Nsga2::new(config);
+            let initial_solutions = (0..nsga2.config.population_size)
+                .map(|_| KnapsackSolution::new_random(None, &problem, &mut rng).unwrap())
+                .collect();
+            let result = nsga2.execute(initial_solutions, &mut rng).unwrap();
+
+            // The front is mutually non-dominated and reaches the single-objective optimum
+            for first in result.pareto_front.iter() {
+                for second in result.pareto_front.iter() {
+                    assert!(!dominates(&first.objectives(), &second.objectives()));
+                }
+            }
+            let best_value = result
+                .pareto_front
+                .iter()
+                .map(|solution| solution.value)
+                .fold(f64::NEG_INFINITY, f64::max);
+            assert!(
+                best_value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                best_value
+            )
+        }
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        ParallelTemperingAlgorithm, ParallelTemperingConfig,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_parallel_tempering() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = ParallelTemperingConfig::new(1000, 4, 0.5, 62.5, 20, None).unwrap();
+            for (temperature, expected) in config.temperatures().iter().zip([0.5, 2.5, 12.5, 62.5])
+            {
+                assert!((temperature - expected).abs() < 1e-9);
+            }
+            let pt = ParallelTemperingAlgorithm::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+            let result = pt.execute(initial_solution, &mut rng).unwrap();
+            assert_eq!(result.number_iterations, 1000);
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+}
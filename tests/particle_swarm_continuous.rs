@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        ParticleSwarmAlgorithm, ParticleSwarmConfig, SwarmTopology,
+    };
+    use optimization_metaheuristics::problems::{
+        BenchmarkFunction, ContinuousProblem, ContinuousSolution,
+    };
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn test_continuous_particle_swarm() {
+        let problems = [
+            ContinuousProblem::new(BenchmarkFunction::Sphere, &[(-5.0, 5.0); 5]).unwrap(),
+            ContinuousProblem::new(BenchmarkFunction::Rosenbrock, &[(-2.0, 2.0); 2]).unwrap(),
+            ContinuousProblem::new(BenchmarkFunction::Ackley, &[(-5.0, 5.0); 3]).unwrap(),
+        ];
+        for topology in [SwarmTopology::Global, SwarmTopology::Ring { neighbors: 1 }] {
+            for problem in problems.iter() {
+                let mut rng = SmallRng::seed_from_u64(654321);
+                let config = ParticleSwarmConfig {
+                    topology,
+                    ..Default::default()
+                };
+                let pso = ParticleSwarmAlgorithm::new(config);
+                let initial_solution = ContinuousSolution::new_random(problem, &mut rng).unwrap();
+                let result = pso.execute(initial_solution, &mut rng).unwrap();
+                assert!(
+                    (result.solution.value - problem.optimal_value.unwrap()).abs() < 1e-4,
+                    "Expected {}, found {} with {:?}.",
+                    problem.optimal_value.unwrap(),
+                    result.solution.value,
+                    topology
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{RandomSearch, RandomSearchConfig};
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_random_search() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = RandomSearchConfig::new(200, None).unwrap();
+            let random_search = RandomSearch::new(config);
+            let result = random_search
+                .execute(
+                    |rng| KnapsackSolution::new_random(None, &problem, rng),
+                    &mut rng,
+                )
+                .unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+}
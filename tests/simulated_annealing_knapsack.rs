@@ -1,13 +1,16 @@
 #[cfg(test)]
 mod tests {
     use optimization_metaheuristics::algorithms::{
-        SimulatedAnnealingAlgorithm, SimulatedAnnealingConfig,
+        AdaptiveCooling, CoolingSchedule, ExponentialPlateauCooling, LinearCooling,
+        LogarithmicCooling, LundyMeesCooling, ReheatStrategy, RestartStrategy,
+        SimulatedAnnealingAlgorithm, SimulatedAnnealingConfig, TemperatureCalibration,
     };
     use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
     use rand::SeedableRng;
     use rand::rngs::SmallRng;
     use std::fs;
     use std::io;
+    use std::sync::Arc;
 
     #[test]
     fn test_knapsack_simulated_annealing() {
@@ -40,4 +43,119 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn test_knapsack_simulated_annealing_cooling_schedules() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let schedules: Vec<Arc<dyn CoolingSchedule>> = vec![
+                Arc::new(LinearCooling::new(0.1).unwrap()),
+                Arc::new(LogarithmicCooling),
+                Arc::new(LundyMeesCooling::new(0.001).unwrap()),
+                Arc::new(ExponentialPlateauCooling::new(0.9, 50).unwrap()),
+                Arc::new(AdaptiveCooling::new(0.3, 0.99).unwrap()),
+            ];
+            for schedule in schedules {
+                let mut rng = SmallRng::seed_from_u64(654321);
+                let config = SimulatedAnnealingConfig {
+                    max_iterations: 1_000,
+                    initial_temperature: 100.0,
+                    cooling_schedule: Some(schedule),
+                    ..Default::default()
+                };
+                let sa = SimulatedAnnealingAlgorithm::new(config);
+                let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+                let result = sa.execute(initial_solution, &mut rng).unwrap();
+                assert!(
+                    result.solution.value == problem.optimal_value.unwrap(),
+                    "Expected {}, found {} with {:?}.",
+                    problem.optimal_value.unwrap(),
+                    result.solution.value,
+                    sa.config.cooling_schedule
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn test_knapsack_simulated_annealing_temperature_calibration() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = SimulatedAnnealingConfig {
+                max_iterations: 1_000,
+                cooling_rate: 0.995,
+                temperature_calibration: Some(TemperatureCalibration::default()),
+                ..Default::default()
+            };
+            let sa = SimulatedAnnealingAlgorithm::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+            let temperature = sa
+                .calibrate_temperature(
+                    &initial_solution,
+                    &TemperatureCalibration::default(),
+                    &mut rng,
+                )
+                .unwrap();
+            assert!(temperature > sa.config.initial_temperature);
+            let result = sa.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
+
+    #[test]
+    fn test_knapsack_simulated_annealing_reheat() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = SimulatedAnnealingConfig {
+                max_iterations: 1_000,
+                cooling_rate: 0.9,
+                initial_temperature: 10.0,
+                reheat_strategy: Some(
+                    ReheatStrategy::new(50, Some(10.0), RestartStrategy::Random).unwrap(),
+                ),
+                ..Default::default()
+            };
+            let sa = SimulatedAnnealingAlgorithm::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+            let result = sa.execute(initial_solution, &mut rng).unwrap();
+            assert!(result.number_reheats() > 0);
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value
+            )
+        }
+    }
 }
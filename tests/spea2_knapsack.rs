@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{Spea2, Spea2Config, dominates};
+    use optimization_metaheuristics::problems::{
+        KnapsackProblem, KnapsackSolution, MultiObjectiveSolution,
+    };
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_spea2() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = Spea2Config::new(200, 60, 60, 0.2, None).unwrap();
+            let spea2 = Spea2::new(config);
+            let initial_solutions = (0..spea2.config.population_size)
+                .map(|_| KnapsackSolution::new_random(None, &problem, &mut rng).unwrap())
+                .collect();
+            let result = spea2.execute(initial_solutions, &mut rng).unwrap();
+
+            // The front is mutually non-dominated and reaches the single-objective optimum
+            for first in result.pareto_front.iter() {
+                for second in result.pareto_front.iter() {
+                    assert!(!dominates(&first.objectives(), &second.objectives()));
+                }
+            }
+            let best_value = result
+                .pareto_front
+                .iter()
+                .map(|solution| solution.value)
+                .fold(f64::NEG_INFINITY, f64::max);
+            assert!(
+                best_value == problem.optimal_value.unwrap(),
+                "Expected {}, found {}.",
+                problem.optimal_value.unwrap(),
+                best_value
+            )
+        }
+    }
+
+    #[test]
+    fn test_knapsack_spea2_archive_truncation() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        for problem in problems {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = Spea2Config::new(200, 60, 3, 0.2, None).unwrap();
+            let spea2 = Spea2::new(config);
+            let initial_solutions = (0..spea2.config.population_size)
+                .map(|_| KnapsackSolution::new_random(None, &problem, &mut rng).unwrap())
+                .collect();
+            let result = spea2.execute(initial_solutions, &mut rng).unwrap();
+
+            // The truncation keeps the archive size and the boundary of the front, here the
+            // empty knapsack
+            assert!(result.pareto_front.len() <= 3);
+            assert!(
+                result
+                    .pareto_front
+                    .iter()
+                    .any(|solution| solution.objectives()[1] == 0.0)
+            );
+        }
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        StepCounting, StepCountingConfig, StepCountingHillClimbing,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_step_counting_hill_climbing() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let countings = [
+            StepCounting::AllMoves,
+            StepCounting::AcceptedMoves,
+            StepCounting::ImprovingMoves,
+        ];
+        for (problem, counting) in problems
+            .iter()
+            .flat_map(|problem| countings.iter().map(move |c| (problem, *c)))
+        {
+            // The search is local, keep the best of a few independent runs
+            let best_value = (0..5)
+                .map(|seed| {
+                    let mut rng = SmallRng::seed_from_u64(654321 + seed);
+                    let config = StepCountingConfig::new(2000, 50, counting, None).unwrap();
+                    let schc = StepCountingHillClimbing::new(config);
+                    let initial_solution = KnapsackSolution::new(vec![], problem).unwrap();
+                    let result = schc.execute(initial_solution, &mut rng).unwrap();
+                    result.solution.value
+                })
+                .fold(f64::NEG_INFINITY, f64::max);
+            assert!(
+                best_value == problem.optimal_value.unwrap(),
+                "Expected {}, found {} with {:?}.",
+                problem.optimal_value.unwrap(),
+                best_value,
+                counting
+            )
+        }
+    }
+}
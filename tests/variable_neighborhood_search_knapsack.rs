@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use optimization_metaheuristics::algorithms::{
+        VariableNeighborhoodCompatible, VariableNeighborhoodSearch,
+        VariableNeighborhoodSearchConfig, VariableNeighborhoodVariant,
+    };
+    use optimization_metaheuristics::problems::{KnapsackProblem, KnapsackSolution};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_knapsack_variable_neighborhood_search() {
+        let files = fs::read_dir("./tests/knapsack")
+            .unwrap()
+            .map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, io::Error>>()
+            .unwrap();
+        let problems: Vec<KnapsackProblem> = files
+            .iter()
+            .flat_map(|file| KnapsackProblem::load_from_file(file).unwrap())
+            .collect();
+        let variants = [
+            VariableNeighborhoodVariant::Basic,
+            VariableNeighborhoodVariant::General,
+        ];
+        for (problem, variant) in problems
+            .iter()
+            .flat_map(|problem| variants.iter().map(move |v| (problem, *v)))
+        {
+            let mut rng = SmallRng::seed_from_u64(654321);
+            let config = VariableNeighborhoodSearchConfig::new(100, variant, 3, None).unwrap();
+            let vns = VariableNeighborhoodSearch::new(config);
+            let initial_solution = KnapsackSolution::new(vec![], problem).unwrap();
+            let result = vns.execute(initial_solution, &mut rng).unwrap();
+            assert!(
+                result.solution.value == problem.optimal_value.unwrap(),
+                "Expected {}, found {} with {:?}.",
+                problem.optimal_value.unwrap(),
+                result.solution.value,
+                variant
+            )
+        }
+    }
+
+    #[test]
+    fn test_knapsack_variable_neighborhood_descent() {
+        // Only flipping the three items at once escapes the heavy item
+        let problem =
+            KnapsackProblem::new(&[10.0, 6.0, 6.0], &[10.0, 5.0, 5.0], 10.0, Some(12.0)).unwrap();
+        let mut rng = SmallRng::seed_from_u64(654321);
+        let initial_solution = KnapsackSolution::new(vec![0], &problem).unwrap();
+        assert_eq!(initial_solution.neighbors(0, 3).unwrap().count(), 3);
+        assert_eq!(initial_solution.neighbors(1, 3).unwrap().count(), 2);
+        assert_eq!(initial_solution.neighbors(2, 3).unwrap().count(), 1);
+        let config = VariableNeighborhoodSearchConfig::new(
+            100,
+            VariableNeighborhoodVariant::Descent,
+            3,
+            None,
+        )
+        .unwrap();
+        let vns = VariableNeighborhoodSearch::new(config);
+        let result = vns.execute(initial_solution, &mut rng).unwrap();
+        assert_eq!(result.solution.value, 12.0);
+    }
+
+    #[test]
+    fn test_knapsack_variable_neighborhood_move_size() {
+        let problem = KnapsackProblem::new(&[1.0; 200], &[1.0; 200], 100.0, Some(100.0)).unwrap();
+        let initial_solution = KnapsackSolution::new(vec![], &problem).unwrap();
+        assert_eq!(
+            initial_solution.neighbors(2, 2).unwrap().count(),
+            200 * 199 / 2
+        );
+        assert!(
+            VariableNeighborhoodSearchConfig::new(
+                100,
+                VariableNeighborhoodVariant::Descent,
+                0,
+                None
+            )
+            .is_err()
+        );
+
+        // The descent stops as soon as the threshold is reached
+        let mut rng = SmallRng::seed_from_u64(654321);
+        let config = VariableNeighborhoodSearchConfig::new(
+            1000,
+            VariableNeighborhoodVariant::Descent,
+            2,
+            Some(-10.0),
+        )
+        .unwrap();
+        let vns = VariableNeighborhoodSearch::new(config);
+        let result = vns.execute(initial_solution, &mut rng).unwrap();
+        assert!(result.solution.value > 10.0 && result.solution.value < 20.0);
+    }
+}